@@ -35,6 +35,14 @@ pub static EXPORT_BATCH_SIZE: Lazy<u32> = lazy_env_parse!("SURREAL_EXPORT_BATCH_
 pub static MAX_STREAM_BATCH_SIZE: Lazy<u32> =
 	lazy_env_parse!("SURREAL_MAX_STREAM_BATCH_SIZE", u32, 1000);
 
+/// The maximum number of values which are sent in each chunk of a streamed RPC response.
+pub static RPC_STREAM_CHUNK_SIZE: Lazy<usize> =
+	lazy_env_parse!("SURREAL_RPC_STREAM_CHUNK_SIZE", usize, 100);
+
+/// The maximum number of prepared statements which are stored for each RPC session.
+pub static RPC_PREPARED_STATEMENTS_LIMIT: Lazy<usize> =
+	lazy_env_parse!("SURREAL_RPC_PREPARED_STATEMENTS_LIMIT", usize, 100);
+
 /// Forward all signup/signin/authenticate query errors to a client performing authentication. Do not use in production.
 pub static INSECURE_FORWARD_ACCESS_ERRORS: Lazy<bool> =
 	lazy_env_parse!("SURREAL_INSECURE_FORWARD_ACCESS_ERRORS", bool, false);
@@ -1,6 +1,7 @@
 use crate::cnf::PROTECTED_PARAM_NAMES;
 use crate::ctx::canceller::Canceller;
 use crate::ctx::reason::Reason;
+use crate::dbs::capabilities::Capability;
 #[cfg(feature = "http")]
 use crate::dbs::capabilities::NetTarget;
 use crate::dbs::{Capabilities, Notification};
@@ -8,7 +9,7 @@ use crate::err::Error;
 use crate::idx::planner::executor::QueryExecutor;
 use crate::idx::planner::{IterationStage, QueryPlanner};
 use crate::idx::trees::store::IndexStores;
-use crate::kvs::Transaction;
+use crate::kvs::{QueryClock, Transaction};
 use crate::sql::value::Value;
 use channel::Sender;
 use std::borrow::Cow;
@@ -65,6 +66,8 @@ pub struct MutableContext {
 	index_stores: IndexStores,
 	// Capabilities
 	capabilities: Arc<Capabilities>,
+	// The clock used for the current time
+	clock: QueryClock,
 	#[cfg(any(
 		feature = "kv-mem",
 		feature = "kv-surrealkv",
@@ -129,6 +132,7 @@ impl MutableContext {
 			query_executor: None,
 			iteration_stage: None,
 			capabilities: Arc::new(capabilities),
+			clock: QueryClock::default(),
 			index_stores,
 			#[cfg(any(
 				feature = "kv-mem",
@@ -158,6 +162,7 @@ impl MutableContext {
 			query_executor: None,
 			iteration_stage: None,
 			capabilities: Arc::new(Capabilities::default()),
+			clock: QueryClock::default(),
 			index_stores: IndexStores::default(),
 			#[cfg(any(
 				feature = "kv-mem",
@@ -183,6 +188,7 @@ impl MutableContext {
 			query_executor: parent.query_executor.clone(),
 			iteration_stage: parent.iteration_stage.clone(),
 			capabilities: parent.capabilities.clone(),
+			clock: parent.clock.clone(),
 			index_stores: parent.index_stores.clone(),
 			#[cfg(any(
 				feature = "kv-mem",
@@ -219,6 +225,7 @@ impl MutableContext {
 			query_executor: parent.query_executor.clone(),
 			iteration_stage: parent.iteration_stage.clone(),
 			capabilities: parent.capabilities.clone(),
+			clock: parent.clock.clone(),
 			index_stores: parent.index_stores.clone(),
 			#[cfg(any(
 				feature = "kv-mem",
@@ -400,6 +407,16 @@ impl MutableContext {
 		self.capabilities = Arc::new(caps);
 	}
 
+	/// Set the clock used for the current time in this context
+	pub fn add_clock(&mut self, clock: QueryClock) {
+		self.clock = clock;
+	}
+
+	/// Get the clock used for the current time in this context
+	pub fn clock(&self) -> &QueryClock {
+		&self.clock
+	}
+
 	/// Get the capabilities for this context
 	#[allow(dead_code)]
 	pub fn get_capabilities(&self) -> Arc<Capabilities> {
@@ -410,7 +427,10 @@ impl MutableContext {
 	#[allow(dead_code)]
 	pub fn check_allowed_scripting(&self) -> Result<(), Error> {
 		if !self.capabilities.allows_scripting() {
-			return Err(Error::ScriptingNotAllowed);
+			return Err(Error::CapabilityDenied {
+				capability: Capability::Scripting,
+				target: None,
+			});
 		}
 		Ok(())
 	}
@@ -418,7 +438,10 @@ impl MutableContext {
 	/// Check if a function is allowed
 	pub fn check_allowed_function(&self, target: &str) -> Result<(), Error> {
 		if !self.capabilities.allows_function_name(target) {
-			return Err(Error::FunctionNotAllowed(target.to_string()));
+			return Err(Error::CapabilityDenied {
+				capability: Capability::Function,
+				target: Some(target.to_string()),
+			});
 		}
 		Ok(())
 	}
@@ -435,7 +458,10 @@ impl MutableContext {
 			{
 				Ok(())
 			}
-			_ => Err(Error::NetTargetNotAllowed(target.to_string())),
+			_ => Err(Error::CapabilityDenied {
+				capability: Capability::Network,
+				target: Some(target.to_string()),
+			}),
 		}
 	}
 }
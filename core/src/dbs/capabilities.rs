@@ -10,6 +10,25 @@ pub trait Target<Item: ?Sized = Self> {
 	fn matches(&self, elem: &Item) -> bool;
 }
 
+/// A capability which can be allowed or denied
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum Capability {
+	Scripting,
+	Function,
+	Network,
+}
+
+impl fmt::Display for Capability {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Scripting => write!(f, "scripting"),
+			Self::Function => write!(f, "function"),
+			Self::Network => write!(f, "network"),
+		}
+	}
+}
+
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
 #[non_exhaustive]
 pub struct FuncTarget(pub String, pub Option<String>);
@@ -352,6 +371,22 @@ impl Capabilities {
 	pub fn allows_network_target(&self, target: &NetTarget) -> bool {
 		self.allow_net.matches(target) && !self.deny_net.matches(target)
 	}
+
+	pub fn allowed_functions(&self) -> &Targets<FuncTarget> {
+		&self.allow_funcs
+	}
+
+	pub fn denied_functions(&self) -> &Targets<FuncTarget> {
+		&self.deny_funcs
+	}
+
+	pub fn allowed_network_targets(&self) -> &Targets<NetTarget> {
+		&self.allow_net
+	}
+
+	pub fn denied_network_targets(&self) -> &Targets<NetTarget> {
+		&self.deny_net
+	}
 }
 
 #[cfg(test)]
@@ -7,6 +7,7 @@ use crate::dbs::QueryType;
 use crate::err::Error;
 use crate::iam::Action;
 use crate::iam::ResourceKind;
+use crate::kvs::Consistency;
 use crate::kvs::Transaction;
 use crate::kvs::TransactionType;
 use crate::kvs::{Datastore, LockType::*, TransactionType::*};
@@ -31,13 +32,15 @@ pub(crate) struct Executor<'a> {
 	err: bool,
 	kvs: &'a Datastore,
 	txn: Option<Arc<Transaction>>,
+	consistency: Consistency,
 }
 
 impl<'a> Executor<'a> {
-	pub fn new(kvs: &'a Datastore) -> Executor<'a> {
+	pub fn new(kvs: &'a Datastore, consistency: Consistency) -> Executor<'a> {
 		Executor {
 			kvs,
 			txn: None,
+			consistency,
 			err: false,
 		}
 	}
@@ -54,7 +57,11 @@ impl<'a> Executor<'a> {
 	async fn begin(&mut self, write: TransactionType) -> bool {
 		match self.txn.as_ref() {
 			Some(_) => false,
-			None => match self.kvs.transaction(write, Optimistic).await {
+			None => match self
+				.kvs
+				.transaction_with_consistency(write, Optimistic, self.consistency)
+				.await
+			{
 				Ok(v) => {
 					self.txn = Some(Arc::new(v));
 					true
@@ -1,6 +1,7 @@
 use crate::ctx::MutableContext;
 use crate::iam::Auth;
 use crate::iam::{Level, Role};
+use crate::kvs::Consistency;
 use crate::sql::value::Value;
 use chrono::Utc;
 use std::sync::Arc;
@@ -31,6 +32,8 @@ pub struct Session {
 	pub rd: Option<Value>,
 	/// The current expiration time of the session
 	pub exp: Option<i64>,
+	/// The read consistency level of the session
+	pub cl: Consistency,
 }
 
 impl Session {
@@ -58,6 +61,12 @@ impl Session {
 		self
 	}
 
+	/// Set the read consistency level of the session
+	pub fn with_consistency(mut self, cl: Consistency) -> Session {
+		self.cl = cl;
+		self
+	}
+
 	/// Retrieves the selected namespace
 	pub(crate) fn ns(&self) -> Option<Arc<str>> {
 		self.ns.as_deref().map(Into::into)
@@ -145,6 +154,7 @@ impl Session {
 			tk: None,
 			rd: Some(rid),
 			exp: None,
+			cl: Default::default(),
 		}
 	}
 
@@ -1,3 +1,4 @@
+use crate::dbs::capabilities::Capability;
 use crate::iam::Error as IamError;
 use crate::idx::ft::MatchRef;
 use crate::idx::trees::vector::SharedVector;
@@ -22,6 +23,7 @@ use fst::Error as FstError;
 use jsonwebtoken::errors::Error as JWTError;
 use object_store::Error as ObjectStoreError;
 use revision::Error as RevisionError;
+use serde::ser::SerializeStruct;
 use serde::Serialize;
 use std::io::Error as IoError;
 use std::string::FromUtf8Error;
@@ -69,10 +71,26 @@ pub enum Error {
 	#[error("There was a problem with the underlying datastore: {0}")]
 	Ds(String),
 
+	/// There was a problem with the underlying datastore, caused by another error
+	#[error("There was a problem with the underlying datastore: {message}")]
+	DsSource {
+		message: String,
+		#[source]
+		source: Box<dyn std::error::Error + Send + Sync>,
+	},
+
 	/// There was a problem with a datastore transaction
 	#[error("There was a problem with a datastore transaction: {0}")]
 	Tx(String),
 
+	/// There was a problem with a datastore transaction, caused by another error
+	#[error("There was a problem with a datastore transaction: {message}")]
+	TxSource {
+		message: String,
+		#[source]
+		source: Box<dyn std::error::Error + Send + Sync>,
+	},
+
 	/// There was an error when starting a new datastore transaction
 	#[error("There was an error when starting a new datastore transaction")]
 	TxFailure,
@@ -105,6 +123,10 @@ pub enum Error {
 	#[error("Transaction is too large")]
 	TxTooLarge,
 
+	/// The transaction failed due to a transient conflict, and can be retried
+	#[error("There was a transient conflict in a datastore transaction: {0}")]
+	TxRetryable(String),
+
 	/// No namespace has been selected
 	#[error("Specify a namespace to use")]
 	NsEmpty,
@@ -674,6 +696,10 @@ pub enum Error {
 	#[error("Cannot negate the value '{0}'")]
 	TryNeg(String),
 
+	/// The result of an arithmetic operation is out of the integer range
+	#[error("Failed to compute '{0}', as the operation results in an arithmetic overflow")]
+	ArithmeticOverflow(String),
+
 	/// It's is not possible to convert between the two types
 	#[error("Cannot convert from '{0}' to '{1}'")]
 	TryFrom(String, &'static str),
@@ -780,17 +806,12 @@ pub enum Error {
 	//
 	// Capabilities
 	//
-	/// Scripting is not allowed
-	#[error("Scripting functions are not allowed")]
-	ScriptingNotAllowed,
-
-	/// Function is not allowed
-	#[error("Function '{0}' is not allowed to be executed")]
-	FunctionNotAllowed(String),
-
-	/// Network target is not allowed
-	#[error("Access to network target '{0}' is not allowed")]
-	NetTargetNotAllowed(String),
+	/// A capability is not allowed
+	#[error("{}", capability_denied(.capability, .target.as_deref()))]
+	CapabilityDenied {
+		capability: Capability,
+		target: Option<String>,
+	},
 
 	//
 	// Authentication / Signup
@@ -1075,6 +1096,13 @@ pub enum Error {
 	#[doc(hidden)]
 	#[error("The underlying datastore does not support versioned queries")]
 	UnsupportedVersionedQueries,
+
+	/// An error annotated with the context in which it occurred
+	#[error("{context}: {source}")]
+	Contextual {
+		context: String,
+		source: Box<Error>,
+	},
 }
 
 impl From<Error> for String {
@@ -1130,7 +1158,11 @@ impl From<tikv::Error> for Error {
 			tikv::Error::DuplicateKeyInsertion => Error::TxKeyAlreadyExists,
 			tikv::Error::KeyError(ke) if ke.abort.contains("KeyTooLarge") => Error::TxKeyTooLarge,
 			tikv::Error::RegionError(re) if re.raft_entry_too_large.is_some() => Error::TxTooLarge,
-			_ => Error::Tx(e.to_string()),
+			tikv::Error::RegionError(_) => Error::TxRetryable(e.to_string()),
+			_ => Error::TxSource {
+				message: e.to_string(),
+				source: Box::new(e),
+			},
 		}
 	}
 }
@@ -1138,28 +1170,41 @@ impl From<tikv::Error> for Error {
 #[cfg(feature = "kv-rocksdb")]
 impl From<rocksdb::Error> for Error {
 	fn from(e: rocksdb::Error) -> Error {
-		Error::Tx(e.to_string())
+		Error::TxSource {
+			message: e.to_string(),
+			source: Box::new(e),
+		}
 	}
 }
 
 #[cfg(feature = "kv-surrealkv")]
 impl From<surrealkv::Error> for Error {
 	fn from(e: surrealkv::Error) -> Error {
-		Error::Tx(e.to_string())
+		Error::TxSource {
+			message: e.to_string(),
+			source: Box::new(e),
+		}
 	}
 }
 
 #[cfg(feature = "kv-fdb")]
 impl From<foundationdb::FdbError> for Error {
 	fn from(e: foundationdb::FdbError) -> Error {
-		Error::Ds(e.to_string())
+		Error::DsSource {
+			message: e.to_string(),
+			source: Box::new(e),
+		}
 	}
 }
 
 #[cfg(feature = "kv-fdb")]
 impl From<foundationdb::TransactionCommitError> for Error {
 	fn from(e: foundationdb::TransactionCommitError) -> Error {
-		Error::Tx(e.to_string())
+		// The commit error holds on to the transaction, so only the cause is kept
+		Error::TxSource {
+			message: e.to_string(),
+			source: Box::new(*e),
+		}
 	}
 }
 
@@ -1200,15 +1245,118 @@ where
 	}
 }
 
+/// Describes a denied capability, and the target it was denied for
+fn capability_denied(capability: &Capability, target: Option<&str>) -> String {
+	match (capability, target) {
+		(Capability::Scripting, _) => String::from("Scripting functions are not allowed"),
+		(Capability::Function, Some(target)) => {
+			format!("Function '{target}' is not allowed to be executed")
+		}
+		(Capability::Network, Some(target)) => {
+			format!("Access to network target '{target}' is not allowed")
+		}
+		(capability, None) => format!("The {capability} capability is not allowed"),
+	}
+}
+
 impl Serialize for Error {
 	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
 	where
 		S: serde::Serializer,
 	{
-		serializer.serialize_str(self.to_string().as_str())
+		match self {
+			// Keep the context separate from the error it annotates
+			Error::Contextual {
+				context,
+				source,
+			} => {
+				let mut val = serializer.serialize_struct("Error", 2)?;
+				val.serialize_field("context", context)?;
+				val.serialize_field("source", source)?;
+				val.end()
+			}
+			e => serializer.serialize_str(e.to_string().as_str()),
+		}
 	}
 }
+#[allow(non_upper_case_globals, non_snake_case)]
 impl Error {
+	/// Scripting is not allowed
+	#[deprecated(note = "use `Error::CapabilityDenied` instead")]
+	pub const ScriptingNotAllowed: Error = Error::CapabilityDenied {
+		capability: Capability::Scripting,
+		target: None,
+	};
+
+	/// Function is not allowed
+	#[deprecated(note = "use `Error::CapabilityDenied` instead")]
+	pub fn FunctionNotAllowed(target: String) -> Error {
+		Error::CapabilityDenied {
+			capability: Capability::Function,
+			target: Some(target),
+		}
+	}
+
+	/// Network target is not allowed
+	#[deprecated(note = "use `Error::CapabilityDenied` instead")]
+	pub fn NetTargetNotAllowed(target: String) -> Error {
+		Error::CapabilityDenied {
+			capability: Capability::Network,
+			target: Some(target),
+		}
+	}
+}
+
+impl Error {
+	/// Annotates this error with the context in which it occurred
+	pub fn with_context(self, context: impl Into<String>) -> Error {
+		Error::Contextual {
+			context: context.into(),
+			source: Box::new(self),
+		}
+	}
+
+	/// Returns the original error, without any added context
+	pub fn root(&self) -> &Error {
+		match self {
+			Error::Contextual {
+				source,
+				..
+			} => source.root(),
+			e => e,
+		}
+	}
+
+	/// Checks whether this error was caused by a transient conflict or
+	/// contention in the datastore, so that retrying the whole transaction
+	/// may succeed.
+	pub fn is_retryable(&self) -> bool {
+		matches!(
+			self.root(),
+			Error::TxConditionNotMet | Error::TxKeyAlreadyExists | Error::TxRetryable(_)
+		)
+	}
+
+	/// Checks whether this error was raised on purpose by the user, either
+	/// with a `THROW` statement or by a failing record access signup or signin
+	/// query, rather than being caused by a fault in the database.
+	pub fn is_user_error(&self) -> bool {
+		matches!(
+			self.root(),
+			Error::Thrown(_)
+				| Error::AccessRecordSignupQueryFailed
+				| Error::AccessRecordSigninQueryFailed
+		)
+	}
+
+	/// Returns the message of an error raised with a `THROW` statement
+	pub fn thrown_message(&self) -> Option<&str> {
+		match self.root() {
+			Error::Thrown(v) => Some(v),
+			_ => None,
+		}
+	}
+
 	pub fn set_check_from_coerce(self, name: String) -> Error {
 		match self {
 			Error::CoerceTo {
@@ -1223,6 +1371,20 @@ impl Error {
 		}
 	}
 
+	pub fn set_check_from_convert(self, name: String) -> Error {
+		match self {
+			Error::ConvertTo {
+				from,
+				into,
+			} => Error::SetCheck {
+				name,
+				value: from.to_string(),
+				check: into,
+			},
+			e => e,
+		}
+	}
+
 	pub fn function_check_from_coerce(self, name: impl Into<String>) -> Error {
 		match self {
 			Error::CoerceTo {
@@ -1237,3 +1399,133 @@ impl Error {
 		}
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	#[allow(deprecated)]
+	fn capability_denied() {
+		let err = Error::ScriptingNotAllowed;
+		assert!(matches!(
+			&err,
+			Error::CapabilityDenied {
+				capability: Capability::Scripting,
+				target: None,
+			}
+		));
+		assert_eq!(err.to_string(), "Scripting functions are not allowed");
+
+		let err = Error::FunctionNotAllowed("http::get".to_string());
+		assert!(matches!(
+			&err,
+			Error::CapabilityDenied {
+				capability: Capability::Function,
+				target: Some(t),
+			} if t == "http::get"
+		));
+		assert_eq!(err.to_string(), "Function 'http::get' is not allowed to be executed");
+
+		let err = Error::NetTargetNotAllowed("http://localhost/".to_string());
+		assert!(matches!(
+			&err,
+			Error::CapabilityDenied {
+				capability: Capability::Network,
+				target: Some(t),
+			} if t == "http://localhost/"
+		));
+		assert_eq!(err.to_string(), "Access to network target 'http://localhost/' is not allowed");
+	}
+
+	#[test]
+	fn with_context() {
+		let err = Error::RealtimeDisabled.with_context("Processing the LIVE statement");
+		assert_eq!(
+			err.to_string(),
+			"Processing the LIVE statement: Unable to perform the realtime query"
+		);
+		assert!(matches!(err.root(), Error::RealtimeDisabled));
+		// Nested context is kept, and the root is still found
+		let err = err.with_context("Running the query");
+		assert!(err.to_string().starts_with("Running the query: Processing the LIVE statement: "));
+		assert!(matches!(err.root(), Error::RealtimeDisabled));
+		assert_eq!(
+			serde_json::to_value(&err).unwrap(),
+			serde_json::json!({
+				"context": "Running the query",
+				"source": {
+					"context": "Processing the LIVE statement",
+					"source": "Unable to perform the realtime query",
+				},
+			})
+		);
+	}
+
+	#[test]
+	fn is_retryable() {
+		assert!(Error::TxConditionNotMet.is_retryable());
+		assert!(Error::TxKeyAlreadyExists.with_context("Creating the record").is_retryable());
+		assert!(!Error::TxKeyTooLarge.is_retryable());
+		assert!(!Error::Tx(String::from("Corruption")).is_retryable());
+	}
+
+	#[test]
+	fn is_user_error() {
+		let err = Error::Thrown(String::from("Invalid email address"));
+		assert!(err.is_user_error());
+		assert_eq!(err.thrown_message(), Some("Invalid email address"));
+		let err = err.with_context("Running the query");
+		assert!(err.is_user_error());
+		assert_eq!(err.thrown_message(), Some("Invalid email address"));
+		assert!(Error::AccessRecordSigninQueryFailed.is_user_error());
+		assert_eq!(Error::AccessRecordSigninQueryFailed.thrown_message(), None);
+		let err = Error::Internal(String::from("Unexpected value"));
+		assert!(!err.is_user_error());
+		assert_eq!(err.thrown_message(), None);
+	}
+
+	#[test]
+	#[cfg(feature = "kv-rocksdb")]
+	fn datastore_error_source() {
+		use std::error::Error as _;
+		let err = rocksdb::DB::open_default("/dev/null/surrealdb").unwrap_err();
+		let message = err.to_string();
+		let err = Error::from(err);
+		// The message is unchanged, but the original error is kept
+		assert_eq!(
+			err.to_string(),
+			format!("There was a problem with a datastore transaction: {message}")
+		);
+		assert!(err.source().is_some());
+	}
+
+	#[test]
+	#[cfg(feature = "kv-mem")]
+	fn is_retryable_mem() {
+		assert!(Error::from(echodb::err::Error::KeyAlreadyExists).is_retryable());
+		assert!(Error::from(echodb::err::Error::ValNotExpectedValue).is_retryable());
+		assert!(!Error::from(echodb::err::Error::TxClosed).is_retryable());
+	}
+
+	#[test]
+	#[cfg(feature = "kv-indxdb")]
+	fn is_retryable_indxdb() {
+		assert!(Error::from(indxdb::err::Error::KeyAlreadyExists).is_retryable());
+		assert!(Error::from(indxdb::err::Error::ValNotExpectedValue).is_retryable());
+		assert!(!Error::from(indxdb::err::Error::TxClosed).is_retryable());
+	}
+
+	#[test]
+	#[cfg(feature = "kv-tikv")]
+	fn is_retryable_tikv() {
+		assert!(Error::from(tikv::Error::DuplicateKeyInsertion).is_retryable());
+		assert!(Error::from(tikv::Error::RegionError(Box::default())).is_retryable());
+		// A raft entry which is too large will never succeed
+		let mut err = tikv::Error::RegionError(Box::default());
+		if let tikv::Error::RegionError(re) = &mut err {
+			re.raft_entry_too_large = Some(Default::default());
+		}
+		assert!(!Error::from(err).is_retryable());
+	}
+}
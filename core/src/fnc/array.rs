@@ -18,9 +18,13 @@ use crate::sql::array::Windows;
 use crate::sql::value::Value;
 use crate::sql::Closure;
 use crate::sql::Function;
+use crate::sql::Object;
 
 use rand::prelude::SliceRandom;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use reblessive::tree::Stk;
+use std::cmp::Ordering;
 use std::mem::size_of_val;
 
 /// Returns an error if an array of this length is too much to allocate.
@@ -68,11 +72,33 @@ pub fn append((mut array, value): (Array, Value)) -> Result<Value, Error> {
 }
 
 pub fn at((array, i): (Array, i64)) -> Result<Value, Error> {
-	let mut idx = i as usize;
-	if i < 0 {
-		idx = (array.len() as i64 + i) as usize;
+	// Negative indexes count back from the end of the array
+	let idx = match i < 0 {
+		true => array.len().checked_sub(i.unsigned_abs() as usize),
+		false => usize::try_from(i).ok(),
+	};
+	Ok(idx.and_then(|idx| array.get(idx)).cloned().unwrap_or_default())
+}
+
+pub fn binary_search(
+	(array, value, validate): (Array, Value, Option<bool>),
+) -> Result<Value, Error> {
+	// Searching an unsorted array gives an unspecified result, unless it is validated first
+	if validate.unwrap_or(false) && !array.0.windows(2).all(|w| w[0] <= w[1]) {
+		return Err(Error::InvalidArguments {
+			name: String::from("array::binary_search"),
+			message: String::from("The array must be sorted in ascending order."),
+		});
 	}
-	Ok(array.get(idx).cloned().unwrap_or_default())
+	// When the value is missing, the index is where it would be inserted
+	let (found, index) = match array.binary_search(&value) {
+		Ok(i) => (true, i),
+		Err(i) => (false, i),
+	};
+	Ok(Value::from(map! {
+		String::from("found") => Value::from(found),
+		String::from("index") => Value::from(index),
+	}))
 }
 
 pub fn boolean_and((lh, rh): (Array, Array)) -> Result<Value, Error> {
@@ -147,6 +173,26 @@ pub fn concat(mut arrays: Vec<Array>) -> Result<Value, Error> {
 	Ok(arr.into())
 }
 
+pub async fn count_where(
+	(stk, ctx, opt, doc): (&mut Stk, &Context, &Options, Option<&CursorDoc>),
+	(array, predicate): (Array, Closure),
+) -> Result<Value, Error> {
+	if predicate.args.len() != 1 {
+		return Err(Error::InvalidArguments {
+			name: String::from("array::count_where"),
+			message: String::from("Expected a closure with one argument, the element"),
+		});
+	}
+	let mut count = 0;
+	for v in array.into_iter() {
+		let fnc = Function::Anonymous(predicate.clone().into(), vec![v]);
+		if fnc.compute(stk, ctx, opt, doc).await?.is_truthy() {
+			count += 1;
+		}
+	}
+	Ok(count.into())
+}
+
 pub fn difference((array, other): (Array, Array)) -> Result<Value, Error> {
 	Ok(array.difference(other).into())
 }
@@ -158,39 +204,17 @@ pub fn distinct((array,): (Array,)) -> Result<Value, Error> {
 pub fn fill(
 	(mut array, value, start, end): (Array, Value, Option<isize>, Option<isize>),
 ) -> Result<Value, Error> {
-	let min = 0;
-	let max = array.len();
-	let negative_max = -(max as isize);
-
-	let start = match start {
-		Some(start) if negative_max <= start && start < 0 => (start + max as isize) as usize,
-		Some(start) if start < negative_max => 0,
-		Some(start) => start as usize,
-		None => min,
-	};
-	let end = match end {
-		Some(end) if negative_max <= end && end < 0 => (end + max as isize) as usize,
-		Some(end) if end < negative_max => 0,
-		Some(end) => end as usize,
-		None => max,
+	let len = array.len();
+	// Negative indexes count back from the end, and every index is kept within the array
+	let index = |i: isize| match i < 0 {
+		true => len.saturating_sub(i.unsigned_abs()),
+		false => (i as usize).min(len),
 	};
-
-	if start == min && end >= max {
-		array.fill(value);
-	} else if end > start {
-		let end_minus_one = end - 1;
-
-		for i in start..end_minus_one {
-			if let Some(elem) = array.get_mut(i) {
-				*elem = value.clone();
-			}
-		}
-
-		if let Some(last_elem) = array.get_mut(end_minus_one) {
-			*last_elem = value;
-		}
+	let start = start.map_or(0, index);
+	let end = end.map_or(len, index);
+	if start < end {
+		array[start..end].fill(value);
 	}
-
 	Ok(array.into())
 }
 
@@ -225,6 +249,37 @@ pub fn first((array,): (Array,)) -> Result<Value, Error> {
 	}
 }
 
+pub fn first_n((array, n): (Array, i64)) -> Result<Value, Error> {
+	if n < 0 {
+		return Err(Error::InvalidArguments {
+			name: String::from("array::first_n"),
+			message: format!("The number of elements must be non-negative, but found {n}."),
+		});
+	}
+	Ok(array.into_iter().take(n as usize).collect::<Vec<_>>().into())
+}
+
+pub async fn flat_map(
+	(stk, ctx, opt, doc): (&mut Stk, &Context, &Options, Option<&CursorDoc>),
+	(array, mapper): (Array, Closure),
+) -> Result<Value, Error> {
+	if mapper.args.len() != 1 {
+		return Err(Error::InvalidArguments {
+			name: String::from("array::flat_map"),
+			message: String::from("Expected a closure with one argument, the element"),
+		});
+	}
+	let mut res = Vec::with_capacity(array.len());
+	for v in array.into_iter() {
+		let fnc = Function::Anonymous(mapper.clone().into(), vec![v]);
+		match fnc.compute(stk, ctx, opt, doc).await? {
+			Value::Array(v) => res.extend(v),
+			v => res.push(v),
+		}
+	}
+	Ok(res.into())
+}
+
 pub fn flatten((array,): (Array,)) -> Result<Value, Error> {
 	Ok(array.flatten().into())
 }
@@ -233,6 +288,48 @@ pub fn group((array,): (Array,)) -> Result<Value, Error> {
 	Ok(array.flatten().uniq().into())
 }
 
+pub async fn group_adjacent(
+	(stk, ctx, opt, doc): (&mut Stk, &Context, &Options, Option<&CursorDoc>),
+	(array, compare): (Array, Closure),
+) -> Result<Value, Error> {
+	// The closure is called with the previous and the current element
+	if compare.args.len() != 2 {
+		return Err(Error::InvalidArguments {
+			name: String::from("array::group_adjacent"),
+			message: String::from(
+				"Expected a closure with two arguments, the previous and the current element",
+			),
+		});
+	}
+	let mut groups: Vec<Vec<Value>> = Vec::new();
+	for v in array.into_iter() {
+		if let Some(last) = groups.last().and_then(|g| g.last()) {
+			let fnc = Function::Anonymous(compare.clone().into(), vec![last.clone(), v.clone()]);
+			if fnc.compute(stk, ctx, opt, doc).await?.is_truthy() {
+				groups.last_mut().unwrap().push(v);
+				continue;
+			}
+		}
+		groups.push(vec![v]);
+	}
+	Ok(groups.into_iter().map(Value::from).collect::<Vec<_>>().into())
+}
+
+pub fn index_of((array, value, from): (Array, Value, Option<i64>)) -> Result<Value, Error> {
+	// Negative index means start from the back
+	let from = match from {
+		Some(i) if i < 0 => (i + array.len() as i64).max(0) as usize,
+		Some(i) => i as usize,
+		None => 0,
+	};
+	Ok(array
+		.iter()
+		.enumerate()
+		.skip(from)
+		.find(|(_, v)| **v == value)
+		.map_or(Value::None, |(i, _)| i.into()))
+}
+
 pub fn insert((mut array, value, index): (Array, Value, Option<i64>)) -> Result<Value, Error> {
 	match index {
 		Some(mut index) => {
@@ -264,6 +361,38 @@ pub fn is_empty((array,): (Array,)) -> Result<Value, Error> {
 	Ok(array.is_empty().into())
 }
 
+pub fn is_sorted((array,): (Array,)) -> Result<Value, Error> {
+	Ok(array.0.windows(2).all(|w| w[0] <= w[1]).into())
+}
+
+pub async fn is_sorted_by(
+	(stk, ctx, opt, doc): (&mut Stk, &Context, &Options, Option<&CursorDoc>),
+	(array, compare): (Array, Closure),
+) -> Result<Value, Error> {
+	// The comparator is called with each pair of adjacent elements
+	if compare.args.len() != 2 {
+		return Err(Error::InvalidArguments {
+			name: String::from("array::is_sorted_by"),
+			message: String::from("Expected a closure with two arguments, the elements to compare"),
+		});
+	}
+	for w in array.0.windows(2) {
+		let fnc = Function::Anonymous(compare.clone().into(), vec![w[0].clone(), w[1].clone()]);
+		// A positive result means the elements are out of order
+		match fnc.compute(stk, ctx, opt, doc).await? {
+			Value::Number(v) if v.is_positive() => return Ok(Value::Bool(false)),
+			Value::Number(_) => {}
+			v => {
+				return Err(Error::InvalidArguments {
+					name: String::from("array::is_sorted_by"),
+					message: format!("Expected the comparator to return a number but found {v}."),
+				})
+			}
+		}
+	}
+	Ok(Value::Bool(true))
+}
+
 pub fn join((arr, sep): (Array, String)) -> Result<Value, Error> {
 	Ok(arr.into_iter().map(Value::as_raw_string).collect::<Vec<_>>().join(&sep).into())
 }
@@ -276,6 +405,37 @@ pub fn last((array,): (Array,)) -> Result<Value, Error> {
 	}
 }
 
+pub fn last_index_of((array, value, from): (Array, Value, Option<i64>)) -> Result<Value, Error> {
+	// Negative index means start from the back
+	let from = match from {
+		Some(i) if i < 0 => i + array.len() as i64,
+		Some(i) => i.min(array.len() as i64 - 1),
+		None => array.len() as i64 - 1,
+	};
+	// The search starts before the beginning of the array
+	if from < 0 {
+		return Ok(Value::None);
+	}
+	Ok(array
+		.iter()
+		.enumerate()
+		.take(from as usize + 1)
+		.rev()
+		.find(|(_, v)| **v == value)
+		.map_or(Value::None, |(i, _)| i.into()))
+}
+
+pub fn last_n((array, n): (Array, i64)) -> Result<Value, Error> {
+	if n < 0 {
+		return Err(Error::InvalidArguments {
+			name: String::from("array::last_n"),
+			message: format!("The number of elements must be non-negative, but found {n}."),
+		});
+	}
+	let skip = array.len().saturating_sub(n as usize);
+	Ok(array.into_iter().skip(skip).collect::<Vec<_>>().into())
+}
+
 pub fn len((array,): (Array,)) -> Result<Value, Error> {
 	Ok(array.len().into())
 }
@@ -374,10 +534,68 @@ pub fn max((array,): (Array,)) -> Result<Value, Error> {
 	Ok(array.into_iter().max().unwrap_or_default())
 }
 
+pub async fn max_by(
+	(stk, ctx, opt, doc): (&mut Stk, &Context, &Options, Option<&CursorDoc>),
+	(array, key): (Array, Closure),
+) -> Result<Value, Error> {
+	select_by((stk, ctx, opt, doc), "array::max_by", array, key, Ordering::Greater).await
+}
+
 pub fn min((array,): (Array,)) -> Result<Value, Error> {
 	Ok(array.into_iter().min().unwrap_or_default())
 }
 
+pub async fn min_by(
+	(stk, ctx, opt, doc): (&mut Stk, &Context, &Options, Option<&CursorDoc>),
+	(array, key): (Array, Closure),
+) -> Result<Value, Error> {
+	select_by((stk, ctx, opt, doc), "array::min_by", array, key, Ordering::Less).await
+}
+
+/// Returns the first element whose key compares as `ord` against the keys of all the others.
+async fn select_by(
+	(stk, ctx, opt, doc): (&mut Stk, &Context, &Options, Option<&CursorDoc>),
+	name: &str,
+	array: Array,
+	key: Closure,
+	ord: Ordering,
+) -> Result<Value, Error> {
+	if key.args.len() != 1 {
+		return Err(Error::InvalidArguments {
+			name: name.to_owned(),
+			message: String::from("Expected a closure with one argument, the element"),
+		});
+	}
+	let mut best: Option<(Value, Value)> = None;
+	for v in array.into_iter() {
+		let fnc = Function::Anonymous(key.clone().into(), vec![v.clone()]);
+		let k = fnc.compute(stk, ctx, opt, doc).await?;
+		// Ties keep the earliest element
+		if best.as_ref().map_or(true, |(b, _)| k.cmp(b) == ord) {
+			best = Some((k, v));
+		}
+	}
+	Ok(best.map(|(_, v)| v).unwrap_or_default())
+}
+
+pub fn pad((mut array, len, value): (Array, usize, Value)) -> Result<Value, Error> {
+	if len > array.len() {
+		limit("array::pad", size_of_val(&value).saturating_mul(len - array.len()))?;
+		array.resize(len, value);
+	}
+	Ok(array.into())
+}
+
+pub fn pad_start((array, len, value): (Array, usize, Value)) -> Result<Value, Error> {
+	if len > array.len() {
+		limit("array::pad_start", size_of_val(&value).saturating_mul(len - array.len()))?;
+		let mut padded = vec![value; len - array.len()];
+		padded.extend(array.0);
+		return Ok(padded.into());
+	}
+	Ok(array.into())
+}
+
 pub fn pop((mut array,): (Array,)) -> Result<Value, Error> {
 	Ok(array.pop().into())
 }
@@ -412,6 +630,64 @@ pub fn range((start, count): (i64, i64)) -> Result<Value, Error> {
 	}
 }
 
+pub async fn ranked(
+	(stk, ctx, opt, doc): (&mut Stk, &Context, &Options, Option<&CursorDoc>),
+	(array, key, desc): (Array, Closure, Option<bool>),
+) -> Result<Value, Error> {
+	if key.args.len() != 1 {
+		return Err(Error::InvalidArguments {
+			name: String::from("array::ranked"),
+			message: String::from("Expected a closure with one argument, the element"),
+		});
+	}
+	let mut keyed = Vec::with_capacity(array.len());
+	for v in array.into_iter() {
+		let fnc = Function::Anonymous(key.clone().into(), vec![v.clone()]);
+		keyed.push((fnc.compute(stk, ctx, opt, doc).await?, v));
+	}
+	// The sort is stable, so tied elements keep their original order
+	match desc.unwrap_or(false) {
+		true => keyed.sort_by(|(a, _), (b, _)| b.cmp(a)),
+		false => keyed.sort_by(|(a, _), (b, _)| a.cmp(b)),
+	}
+	// Elements with equal keys share a dense rank
+	let mut rank = 0i64;
+	let mut last = None;
+	let mut res = Vec::with_capacity(keyed.len());
+	for (k, v) in keyed {
+		if last.as_ref() != Some(&k) {
+			rank += 1;
+		}
+		res.push(Value::from(map! {
+			String::from("item") => v,
+			String::from("rank") => Value::from(rank),
+		}));
+		last = Some(k);
+	}
+	Ok(res.into())
+}
+
+pub async fn reduce_right(
+	(stk, ctx, opt, doc): (&mut Stk, &Context, &Options, Option<&CursorDoc>),
+	(array, init, reducer): (Array, Value, Closure),
+) -> Result<Value, Error> {
+	// The reducer is called with both the accumulator and the element
+	if reducer.args.len() != 2 {
+		return Err(Error::InvalidArguments {
+			name: String::from("array::reduce_right"),
+			message: String::from(
+				"Expected a closure with two arguments, the accumulator and the element",
+			),
+		});
+	}
+	let mut acc = init;
+	for v in array.into_iter().rev() {
+		let fnc = Function::Anonymous(reducer.clone().into(), vec![acc, v]);
+		acc = fnc.compute(stk, ctx, opt, doc).await?;
+	}
+	Ok(acc)
+}
+
 pub fn remove((mut array, mut index): (Array, i64)) -> Result<Value, Error> {
 	// Negative index means start from the back
 	if index < 0 {
@@ -437,6 +713,36 @@ pub fn reverse((mut array,): (Array,)) -> Result<Value, Error> {
 	Ok(array.into())
 }
 
+pub async fn running(
+	(stk, ctx, opt, doc): (&mut Stk, &Context, &Options, Option<&CursorDoc>),
+	(array, window, mapper, partial): (Array, usize, Closure, Option<bool>),
+) -> Result<Value, Error> {
+	if mapper.args.len() != 1 {
+		return Err(Error::InvalidArguments {
+			name: String::from("array::running"),
+			message: String::from("Expected a closure with one argument, the window"),
+		});
+	}
+	if window == 0 {
+		return Err(Error::InvalidArguments {
+			name: String::from("array::running"),
+			message: String::from("The window size must be an integer greater than 0."),
+		});
+	}
+	// Shorter windows at the start are only included when asked for
+	let first = match partial.unwrap_or(false) {
+		true => 1,
+		false => window,
+	};
+	let mut res = Vec::with_capacity(array.len());
+	for end in first..=array.len() {
+		let slice = Value::from(array[end.saturating_sub(window)..end].to_vec());
+		let fnc = Function::Anonymous(mapper.clone().into(), vec![slice]);
+		res.push(fnc.compute(stk, ctx, opt, doc).await?);
+	}
+	Ok(res.into())
+}
+
 pub fn shuffle((mut array,): (Array,)) -> Result<Value, Error> {
 	let mut rng = rand::thread_rng();
 	array.shuffle(&mut rng);
@@ -494,6 +800,36 @@ pub fn sort((mut array, order): (Array, Option<Value>)) -> Result<Value, Error>
 	}
 }
 
+pub fn splice(
+	(mut array, start, count, items): (Array, i64, i64, Option<Array>),
+) -> Result<Value, Error> {
+	let len = array.len() as i64;
+	// Negative index means start from the back
+	let idx = match start {
+		v if v < 0 => v + len,
+		v => v,
+	};
+	if idx < 0 || idx > len {
+		return Err(Error::InvalidArguments {
+			name: String::from("array::splice"),
+			message: format!(
+				"The start index {start} is out of range for an array of length {len}."
+			),
+		});
+	}
+	if count < 0 || count > len - idx {
+		return Err(Error::InvalidArguments {
+			name: String::from("array::splice"),
+			message: format!(
+				"Can not remove {count} elements from index {idx} of an array of length {len}."
+			),
+		});
+	}
+	let (idx, count) = (idx as usize, count as usize);
+	array.splice(idx..idx + count, items.unwrap_or_default());
+	Ok(array.into())
+}
+
 pub fn swap((mut array, from, to): (Array, isize, isize)) -> Result<Value, Error> {
 	let min = 0;
 	let max = array.len();
@@ -525,6 +861,43 @@ pub fn swap((mut array, from, to): (Array, isize, isize)) -> Result<Value, Error
 	Ok(array.into())
 }
 
+pub async fn to_object(
+	(stk, ctx, opt, doc): (&mut Stk, &Context, &Options, Option<&CursorDoc>),
+	(array, key, value, strict): (Array, Closure, Closure, Option<bool>),
+) -> Result<Value, Error> {
+	if key.args.len() != 1 || value.args.len() != 1 {
+		return Err(Error::InvalidArguments {
+			name: String::from("array::to_object"),
+			message: String::from("Expected closures with one argument, the element"),
+		});
+	}
+	let strict = strict.unwrap_or(false);
+	let mut res = Object::default();
+	for arg in array.into_iter() {
+		let fnc = Function::Anonymous(key.clone().into(), vec![arg.clone()]);
+		let k = match fnc.compute(stk, ctx, opt, doc).await? {
+			Value::Strand(k) => k.0,
+			v => {
+				return Err(Error::InvalidArguments {
+					name: String::from("array::to_object"),
+					message: format!("Expected the key closure to return a string but found {v}."),
+				})
+			}
+		};
+		// Later keys overwrite earlier ones, unless collisions are disallowed
+		if strict && res.contains_key(&k) {
+			return Err(Error::InvalidArguments {
+				name: String::from("array::to_object"),
+				message: format!("The key '{k}' was produced more than once."),
+			});
+		}
+		let fnc = Function::Anonymous(value.clone().into(), vec![arg]);
+		let v = fnc.compute(stk, ctx, opt, doc).await?;
+		res.insert(k, v);
+	}
+	Ok(res.into())
+}
+
 pub fn transpose((array,): (Array,)) -> Result<Value, Error> {
 	Ok(array.transpose().into())
 }
@@ -533,6 +906,45 @@ pub fn union((array, other): (Array, Array)) -> Result<Value, Error> {
 	Ok(array.union(other).into())
 }
 
+pub fn weighted_sample((array, count, seed): (Array, usize, Option<u64>)) -> Result<Value, Error> {
+	let invalid = |message: String| Error::InvalidArguments {
+		name: String::from("array::weighted_sample"),
+		message,
+	};
+	// Split each pair into its item and weight
+	let mut pairs = Vec::with_capacity(array.len());
+	for v in array {
+		let (item, weight) = match v {
+			Value::Array(a) if a.len() == 2 => {
+				let mut a = a.0;
+				let weight = a.pop().unwrap_or_default();
+				(a.pop().unwrap_or_default(), weight)
+			}
+			v => return Err(invalid(format!("Expected an [item, weight] pair but found {v}."))),
+		};
+		match weight {
+			Value::Number(n) if n.to_float() >= 0.0 => pairs.push((item, n.to_float())),
+			v => return Err(invalid(format!("Expected a non-negative weight but found {v}."))),
+		}
+	}
+	if !pairs.is_empty() && pairs.iter().all(|(_, w)| *w == 0.0) {
+		return Err(invalid(String::from("At least one weight must be greater than 0.")));
+	}
+	// A seed makes the selection reproducible
+	let mut rng = match seed {
+		Some(seed) => StdRng::seed_from_u64(seed),
+		None => StdRng::from_entropy(),
+	};
+	// Give each item a random key scaled by its weight, and keep the largest keys
+	let mut keyed: Vec<(f64, Value)> = pairs
+		.into_iter()
+		.filter(|(_, w)| *w > 0.0)
+		.map(|(v, w)| (rng.gen::<f64>().ln() / w, v))
+		.collect();
+	keyed.sort_by(|a, b| b.0.total_cmp(&a.0));
+	Ok(keyed.into_iter().take(count).map(|(_, v)| v).collect::<Vec<_>>().into())
+}
+
 pub fn windows((array, window_size): (Array, i64)) -> Result<Value, Error> {
 	let window_size = window_size.max(0) as usize;
 	Ok(array.windows(window_size)?.into())
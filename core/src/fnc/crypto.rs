@@ -1,5 +1,6 @@
 use crate::err::Error;
 use crate::sql::value::Value;
+use crate::sql::Bytes;
 use md5::Digest;
 use md5::Md5;
 use sha1::Sha1;
@@ -10,6 +11,22 @@ pub fn blake3((arg,): (String,)) -> Result<Value, Error> {
 	Ok(blake3::hash(arg.as_bytes()).to_string().into())
 }
 
+pub fn decrypt((key, data): (Value, Bytes)) -> Result<Value, Error> {
+	let key = aead::key("crypto::decrypt", key)?;
+	aead::open(&key, data.into_inner()).map(|v| Value::Bytes(v.into())).ok_or_else(|| {
+		Error::InvalidArguments {
+			name: String::from("crypto::decrypt"),
+			message: String::from("The data could not be decrypted or authenticated."),
+		}
+	})
+}
+
+pub fn encrypt((key, data): (Value, Value)) -> Result<Value, Error> {
+	let key = aead::key("crypto::encrypt", key)?;
+	let data = aead::bytes("crypto::encrypt", data)?;
+	Ok(Value::Bytes(aead::seal(&key, data)?.into()))
+}
+
 pub fn md5((arg,): (String,)) -> Result<Value, Error> {
 	let mut hasher = Md5::new();
 	hasher.update(arg.as_str());
@@ -145,16 +162,58 @@ pub mod bcrypt {
 	}
 }
 
+pub mod jwt {
+
+	use crate::err::Error;
+	use crate::sql::value::Value;
+	use crate::syn;
+	use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+
+	/// Decodes the header and the payload of a JSON Web Token.
+	///
+	/// The signature of the token is NOT verified, so the decoded
+	/// claims can not be trusted and must only be used for inspection.
+	pub fn decode((token,): (String,)) -> Result<Value, Error> {
+		let mut parts = token.split('.');
+		match (parts.next(), parts.next(), parts.next(), parts.next()) {
+			(Some(header), Some(payload), Some(_), None) => Ok(Value::from(map! {
+				"header".to_string() => part(header)?,
+				"payload".to_string() => part(payload)?,
+			})),
+			_ => Err(invalid()),
+		}
+	}
+
+	/// Decodes a single base64url encoded JSON object from a token
+	fn part(val: &str) -> Result<Value, Error> {
+		let val = URL_SAFE_NO_PAD.decode(val).map_err(|_| invalid())?;
+		let val = String::from_utf8(val).map_err(|_| invalid())?;
+		match syn::json(&val) {
+			Ok(val @ Value::Object(_)) => Ok(val),
+			_ => Err(invalid()),
+		}
+	}
+
+	fn invalid() -> Error {
+		Error::InvalidArguments {
+			name: String::from("crypto::jwt::decode"),
+			message: String::from("The token is not a valid JSON Web Token."),
+		}
+	}
+}
+
 pub mod pbkdf2 {
 
-	use super::COST_ALLOWANCE;
+	use super::{aead, COST_ALLOWANCE};
 	use crate::err::Error;
 	use crate::sql::value::Value;
+	use crate::sql::Bytes;
 	use pbkdf2::{
 		password_hash::{PasswordHash, PasswordHasher, SaltString},
 		Pbkdf2,
 	};
 	use rand::rngs::OsRng;
+	use sha2::{Sha256, Sha512};
 
 	pub fn cmp((hash, pass): (String, String)) -> Result<Value, Error> {
 		type Params = <Pbkdf2 as PasswordHasher>::Params;
@@ -178,6 +237,48 @@ pub mod pbkdf2 {
 		let hash = Pbkdf2.hash_password(pass.as_ref(), &salt).unwrap().to_string();
 		Ok(hash.into())
 	}
+
+	/// The maximum number of iterations allowed when deriving a key
+	const PBKDF2_MAX_ITERATIONS: i64 = 10_000_000;
+
+	/// The maximum length in bytes of a derived key
+	const PBKDF2_MAX_LENGTH: i64 = 1024;
+
+	pub fn derive(
+		(pass, salt, iterations, len, hash): (Value, Value, i64, i64, Option<String>),
+	) -> Result<Value, Error> {
+		const NAME: &str = "crypto::pbkdf2::derive";
+		let pass = aead::bytes(NAME, pass)?;
+		let salt = aead::bytes(NAME, salt)?;
+		if !(1..=PBKDF2_MAX_ITERATIONS).contains(&iterations) {
+			return Err(Error::InvalidArguments {
+				name: NAME.to_owned(),
+				message: format!(
+					"The iteration count must be between 1 and {PBKDF2_MAX_ITERATIONS}."
+				),
+			});
+		}
+		if !(1..=PBKDF2_MAX_LENGTH).contains(&len) {
+			return Err(Error::InvalidArguments {
+				name: NAME.to_owned(),
+				message: format!("The key length must be between 1 and {PBKDF2_MAX_LENGTH} bytes."),
+			});
+		}
+		let mut out = vec![0u8; len as usize];
+		match hash.as_deref().unwrap_or("sha256") {
+			"sha256" => pbkdf2::pbkdf2_hmac::<Sha256>(&pass, &salt, iterations as u32, &mut out),
+			"sha512" => pbkdf2::pbkdf2_hmac::<Sha512>(&pass, &salt, iterations as u32, &mut out),
+			h => {
+				return Err(Error::InvalidArguments {
+					name: NAME.to_owned(),
+					message: format!(
+						"Expected the hash to be 'sha256' or 'sha512' but found '{h}'."
+					),
+				})
+			}
+		}
+		Ok(Value::Bytes(Bytes(out)))
+	}
 }
 
 pub mod scrypt {
@@ -214,3 +315,225 @@ pub mod scrypt {
 		Ok(hash.into())
 	}
 }
+
+pub mod totp {
+
+	use crate::ctx::Context;
+	use crate::err::Error;
+	use crate::sql::number::Number;
+	use crate::sql::object::Object;
+	use crate::sql::value::Value;
+	use ring::constant_time::verify_slices_are_equal;
+	use ring::hmac::{self, HMAC_SHA1_FOR_LEGACY_USE_ONLY, HMAC_SHA256, HMAC_SHA512};
+
+	/// The hash algorithm used to compute the one-time password.
+	#[derive(Clone, Copy)]
+	enum Algorithm {
+		Sha1,
+		Sha256,
+		Sha512,
+	}
+
+	/// The parameters used to compute a time-based one-time password,
+	/// defaulting to the values recommended in RFC 6238.
+	struct Config {
+		algorithm: Algorithm,
+		digits: u32,
+		period: u64,
+		window: u64,
+	}
+
+	impl Config {
+		fn parse(name: &str, opts: Option<Object>) -> Result<Self, Error> {
+			let mut config = Config {
+				algorithm: Algorithm::Sha1,
+				digits: 6,
+				period: 30,
+				window: 1,
+			};
+			let Some(opts) = opts else {
+				return Ok(config);
+			};
+			if let Some(v) = opts.get("algorithm") {
+				config.algorithm = match v.clone().as_raw_string().to_lowercase().as_str() {
+					"sha1" => Algorithm::Sha1,
+					"sha256" => Algorithm::Sha256,
+					"sha512" => Algorithm::Sha512,
+					_ => {
+						return Err(invalid(
+							name,
+							"The algorithm must be one of 'sha1', 'sha256' or 'sha512'.",
+						))
+					}
+				};
+			}
+			if let Some(v) = opts.get("digits") {
+				config.digits = match v {
+					Value::Number(Number::Int(n)) if (6..=8).contains(n) => *n as u32,
+					_ => {
+						return Err(invalid(name, "The number of digits must be between 6 and 8."))
+					}
+				};
+			}
+			if let Some(v) = opts.get("period") {
+				config.period = match v {
+					Value::Number(Number::Int(n)) if *n > 0 => *n as u64,
+					Value::Duration(d) if d.as_secs() > 0 => d.as_secs(),
+					_ => {
+						return Err(invalid(
+							name,
+							"The period must be a positive number of seconds.",
+						))
+					}
+				};
+			}
+			if let Some(v) = opts.get("window") {
+				config.window = match v {
+					Value::Number(Number::Int(n)) if (0..=10).contains(n) => *n as u64,
+					_ => return Err(invalid(name, "The window must be between 0 and 10.")),
+				};
+			}
+			Ok(config)
+		}
+	}
+
+	/// Generates a time-based one-time password for a base32 encoded secret.
+	pub fn generate(
+		ctx: &Context,
+		(secret, opts): (String, Option<Object>),
+	) -> Result<Value, Error> {
+		let name = "crypto::totp::generate";
+		let config = Config::parse(name, opts)?;
+		let key = decode(name, &secret)?;
+		let counter = now(ctx) / config.period;
+		Ok(code(&key, counter, &config).into())
+	}
+
+	/// Verifies a time-based one-time password for a base32 encoded secret,
+	/// accepting codes from up to `window` periods before or after the current one.
+	pub fn verify(
+		ctx: &Context,
+		(secret, input, opts): (String, String, Option<Object>),
+	) -> Result<Value, Error> {
+		let name = "crypto::totp::verify";
+		let config = Config::parse(name, opts)?;
+		let key = decode(name, &secret)?;
+		let counter = now(ctx) / config.period;
+		let first = counter.saturating_sub(config.window);
+		let last = counter.saturating_add(config.window);
+		// Check every candidate so that the time taken does not depend on the match
+		let valid = (first..=last)
+			.map(|c| verify_slices_are_equal(code(&key, c, &config).as_bytes(), input.as_bytes()))
+			.map(|res| res.is_ok())
+			.fold(false, |acc, ok| acc | ok);
+		Ok(valid.into())
+	}
+
+	/// Returns the current time in seconds since the Unix epoch.
+	fn now(ctx: &Context) -> u64 {
+		ctx.clock().now().timestamp().max(0) as u64
+	}
+
+	/// Computes the HOTP code for the given counter, as described in RFC 4226.
+	fn code(key: &[u8], counter: u64, config: &Config) -> String {
+		let msg = counter.to_be_bytes();
+		let algorithm = match config.algorithm {
+			Algorithm::Sha1 => HMAC_SHA1_FOR_LEGACY_USE_ONLY,
+			Algorithm::Sha256 => HMAC_SHA256,
+			Algorithm::Sha512 => HMAC_SHA512,
+		};
+		let tag = hmac::sign(&hmac::Key::new(algorithm, key), &msg);
+		let hash = tag.as_ref();
+		let offset = (hash[hash.len() - 1] & 0x0f) as usize;
+		let bin = u32::from_be_bytes([
+			hash[offset] & 0x7f,
+			hash[offset + 1],
+			hash[offset + 2],
+			hash[offset + 3],
+		]);
+		let code = bin % 10u32.pow(config.digits);
+		format!("{code:0width$}", width = config.digits as usize)
+	}
+
+	/// Decodes a RFC 4648 base32 secret, ignoring case, whitespace and padding.
+	fn decode(name: &str, secret: &str) -> Result<Vec<u8>, Error> {
+		let mut out = Vec::with_capacity(secret.len() * 5 / 8);
+		let mut buffer = 0u64;
+		let mut bits = 0;
+		for c in secret.chars().filter(|c| !c.is_whitespace() && *c != '=') {
+			let v = match c.to_ascii_uppercase() {
+				c @ 'A'..='Z' => c as u64 - 'A' as u64,
+				c @ '2'..='7' => c as u64 - '2' as u64 + 26,
+				_ => return Err(invalid(name, "The secret must be a valid base32 string.")),
+			};
+			buffer = (buffer << 5) | v;
+			bits += 5;
+			if bits >= 8 {
+				bits -= 8;
+				out.push((buffer >> bits) as u8);
+				buffer &= (1 << bits) - 1;
+			}
+		}
+		if out.is_empty() {
+			return Err(invalid(name, "The secret must not be empty."));
+		}
+		Ok(out)
+	}
+
+	fn invalid(name: &str, message: &str) -> Error {
+		Error::InvalidArguments {
+			name: name.to_owned(),
+			message: message.to_owned(),
+		}
+	}
+}
+
+/// Symmetric authenticated encryption, using ChaCha20-Poly1305 with a random nonce which is
+/// prepended to the sealed output.
+mod aead {
+
+	use crate::err::Error;
+	use crate::sql::value::Value;
+	use ring::aead::{Aad, LessSafeKey, Nonce, UnboundKey, CHACHA20_POLY1305, NONCE_LEN};
+
+	/// Returns the raw bytes of a string or bytes argument
+	pub fn bytes(name: &str, val: Value) -> Result<Vec<u8>, Error> {
+		match val {
+			Value::Bytes(v) => Ok(v.into_inner()),
+			Value::Strand(v) => Ok(v.0.into_bytes()),
+			v => Err(Error::InvalidArguments {
+				name: name.to_owned(),
+				message: format!("Expected a string or bytes but found {}.", v.kindof()),
+			}),
+		}
+	}
+
+	pub fn key(name: &str, val: Value) -> Result<LessSafeKey, Error> {
+		let key = bytes(name, val)?;
+		UnboundKey::new(&CHACHA20_POLY1305, &key).map(LessSafeKey::new).map_err(|_| {
+			Error::InvalidArguments {
+				name: name.to_owned(),
+				message: format!(
+					"The key must be exactly {} bytes long.",
+					CHACHA20_POLY1305.key_len()
+				),
+			}
+		})
+	}
+
+	pub fn seal(key: &LessSafeKey, mut data: Vec<u8>) -> Result<Vec<u8>, Error> {
+		let nonce: [u8; NONCE_LEN] = rand::random();
+		key.seal_in_place_append_tag(Nonce::assume_unique_for_key(nonce), Aad::empty(), &mut data)
+			.map_err(|_| Error::Unreachable("crypto::encrypt failed to seal the data"))?;
+		Ok([nonce.as_slice(), &data].concat())
+	}
+
+	pub fn open(key: &LessSafeKey, mut data: Vec<u8>) -> Option<Vec<u8>> {
+		if data.len() < NONCE_LEN {
+			return None;
+		}
+		let mut rest = data.split_off(NONCE_LEN);
+		let nonce = Nonce::try_assume_unique_for_key(&data).ok()?;
+		key.open_in_place(nonce, Aad::empty(), &mut rest).ok().map(|v| v.to_vec())
+	}
+}
@@ -12,8 +12,28 @@ use crate::fnc::util::math::spread::Spread;
 use crate::fnc::util::math::top::Top;
 use crate::fnc::util::math::trimean::Trimean;
 use crate::fnc::util::math::variance::Variance;
+use crate::sql::array::Array;
 use crate::sql::number::{Number, Sort};
-use crate::sql::value::{TryPow, Value};
+use crate::sql::value::{TryDiv, TryPow, TryRem, Value};
+use rust_decimal::RoundingStrategy;
+use std::collections::BTreeMap;
+
+/// Returns the share of the array taken up by each distinct value
+#[allow(clippy::mutable_key_type)]
+fn proportions(name: &str, array: Array) -> Result<Vec<f64>, Error> {
+	if array.is_empty() {
+		return Err(Error::InvalidArguments {
+			name: name.to_owned(),
+			message: String::from("The array must not be empty."),
+		});
+	}
+	let mut counts = BTreeMap::new();
+	for v in array.iter() {
+		*counts.entry(v).or_insert(0usize) += 1;
+	}
+	let len = array.len() as f64;
+	Ok(counts.into_values().map(|c| c as f64 / len).collect())
+}
 
 pub fn abs((arg,): (Number,)) -> Result<Value, Error> {
 	Ok(arg.abs().into())
@@ -27,6 +47,10 @@ pub fn acot((arg,): (Number,)) -> Result<Value, Error> {
 	Ok(arg.acot().into())
 }
 
+pub fn approx_eq((a, b, epsilon): (Value, Value, f64)) -> Result<Value, Error> {
+	Ok(a.approx_eq(&b, epsilon).into())
+}
+
 pub fn asin((arg,): (Number,)) -> Result<Value, Error> {
 	Ok(arg.asin().into())
 }
@@ -35,6 +59,27 @@ pub fn atan((arg,): (Number,)) -> Result<Value, Error> {
 	Ok(arg.atan().into())
 }
 
+pub fn binomial((n, k): (i64, i64)) -> Result<Value, Error> {
+	if n < 0 || k < 0 {
+		return Err(Error::InvalidArguments {
+			name: String::from("math::binomial"),
+			message: String::from("The arguments must be non-negative integers."),
+		});
+	}
+	if k > n {
+		return Ok(Value::from(0));
+	}
+	// Each partial result is at most the final result
+	let mut res: i128 = 1;
+	for i in 0..k.min(n - k) {
+		res = res * (n - i) as i128 / (i + 1) as i128;
+		if res > i64::MAX as i128 {
+			return Err(Error::ArithmeticOverflow(format!("math::binomial({n}, {k})")));
+		}
+	}
+	Ok(Value::from(res as i64))
+}
+
 pub fn bottom((array, c): (Vec<Number>, i64)) -> Result<Value, Error> {
 	if c > 0 {
 		Ok(array.bottom(c).into())
@@ -54,6 +99,10 @@ pub fn clamp((arg, min, max): (Number, Number, Number)) -> Result<Value, Error>
 	Ok(arg.clamp(min, max).into())
 }
 
+pub fn copysign((arg, sign): (Number, Number)) -> Result<Value, Error> {
+	Ok(arg.copysign(sign).into())
+}
+
 pub fn cos((arg,): (Number,)) -> Result<Value, Error> {
 	Ok(arg.cos().into())
 }
@@ -65,6 +114,45 @@ pub fn deg2rad((arg,): (Number,)) -> Result<Value, Error> {
 	Ok(arg.deg2rad().into())
 }
 
+pub fn entropy((array,): (Array,)) -> Result<Value, Error> {
+	let p = proportions("math::entropy", array)?;
+	Ok(p.iter().map(|p| p * (1.0 / p).log2()).sum::<f64>().into())
+}
+
+pub fn exponential_moving_average((array, alpha): (Vec<Number>, f64)) -> Result<Value, Error> {
+	if !(alpha > 0.0 && alpha <= 1.0) {
+		return Err(Error::InvalidArguments {
+			name: String::from("math::exponential_moving_average"),
+			message: String::from("The smoothing factor must be greater than 0 and at most 1."),
+		});
+	}
+	// The first value starts the average
+	let mut avg = None;
+	let out: Vec<f64> = array
+		.iter()
+		.map(|v| {
+			let v = v.to_float();
+			let next = avg.map_or(v, |avg| alpha * v + (1.0 - alpha) * avg);
+			avg = Some(next);
+			next
+		})
+		.collect();
+	Ok(Value::Array(out.into()))
+}
+
+pub fn factorial((n,): (i64,)) -> Result<Value, Error> {
+	if n < 0 {
+		return Err(Error::InvalidArguments {
+			name: String::from("math::factorial"),
+			message: String::from("The argument must be a non-negative integer."),
+		});
+	}
+	(2..=n)
+		.try_fold(1i64, |acc, v| acc.checked_mul(v))
+		.map(Value::from)
+		.ok_or_else(|| Error::ArithmeticOverflow(format!("math::factorial({n})")))
+}
+
 pub fn fixed((arg, p): (Number, i64)) -> Result<Value, Error> {
 	if p > 0 {
 		Ok(arg.fixed(p as usize).into())
@@ -80,6 +168,52 @@ pub fn floor((arg,): (Number,)) -> Result<Value, Error> {
 	Ok(arg.floor().into())
 }
 
+pub fn gini((array,): (Array,)) -> Result<Value, Error> {
+	let p = proportions("math::gini", array)?;
+	Ok((1.0 - p.iter().map(|p| p * p).sum::<f64>()).into())
+}
+
+pub fn hypot((a, b): (Number, Number)) -> Result<Value, Error> {
+	Ok(a.hypot(b).into())
+}
+
+pub fn interpolate((points, x): (Array, Number)) -> Result<Value, Error> {
+	let invalid = |message: &str| Error::InvalidArguments {
+		name: String::from("math::interpolate"),
+		message: message.to_owned(),
+	};
+	// Each control point must be an [x, y] pair of numbers
+	let points = points
+		.into_iter()
+		.map(|p| match p {
+			Value::Array(a) => match a.as_slice() {
+				[Value::Number(x), Value::Number(y)] => Ok((x.to_float(), y.to_float())),
+				_ => Err(invalid("Expected each point to be an array of two numbers.")),
+			},
+			_ => Err(invalid("Expected each point to be an array of two numbers.")),
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+	if points.is_empty() {
+		return Err(invalid("Expected at least one point."));
+	}
+	if points.windows(2).any(|w| w[0].0 >= w[1].0) {
+		return Err(invalid("Expected the points to be sorted by strictly increasing x."));
+	}
+	let x = x.to_float();
+	// Values outside of the points are clamped to the endpoints
+	let (first, last) = (points[0], points[points.len() - 1]);
+	if x <= first.0 {
+		return Ok(first.1.into());
+	}
+	if x >= last.0 {
+		return Ok(last.1.into());
+	}
+	// Find the segment which contains x
+	let i = points.partition_point(|p| p.0 <= x);
+	let ((x0, y0), (x1, y1)) = (points[i - 1], points[i]);
+	Ok((y0 + (y1 - y0) * (x - x0) / (x1 - x0)).into())
+}
+
 pub fn interquartile((mut array,): (Vec<Number>,)) -> Result<Value, Error> {
 	Ok(array.sorted().interquartile().into())
 }
@@ -141,6 +275,23 @@ pub fn mode((array,): (Vec<Number>,)) -> Result<Value, Error> {
 	Ok(array.mode().into())
 }
 
+pub fn moving_average((array, window): (Vec<Number>, i64)) -> Result<Value, Error> {
+	let window = match usize::try_from(window) {
+		Ok(w) if w > 0 => w,
+		_ => {
+			return Err(Error::InvalidArguments {
+				name: String::from("math::moving_average"),
+				message: String::from("The window must be an integer greater than 0."),
+			})
+		}
+	};
+	// An array shorter than the window has no complete windows
+	let values: Vec<f64> = array.iter().map(Number::to_float).collect();
+	let out: Vec<f64> =
+		values.windows(window).map(|w| w.iter().sum::<f64>() / window as f64).collect();
+	Ok(Value::Array(out.into()))
+}
+
 pub fn nearestrank((mut array, n): (Vec<Number>, Number)) -> Result<Value, Error> {
 	Ok(array.sorted().nearestrank(n).into())
 }
@@ -157,14 +308,89 @@ pub fn product((array,): (Vec<Number>,)) -> Result<Value, Error> {
 	Ok(array.into_iter().product::<Number>().into())
 }
 
+pub fn quantize((arg, step): (Number, Number)) -> Result<Value, Error> {
+	if !step.is_positive() {
+		return Err(Error::InvalidArguments {
+			name: String::from("math::quantize"),
+			message: String::from("The step must be a number greater than 0."),
+		});
+	}
+	let overflow = || Error::ArithmeticOverflow(format!("math::quantize({arg}, {step})"));
+	match (&arg, &step) {
+		(Number::Int(v), Number::Int(s)) => {
+			let (v, s) = (*v as i128, *s as i128);
+			// Round halfway values away from zero
+			let mut q = v / s;
+			if 2 * (v % s).abs() >= s {
+				q += v.signum();
+			}
+			i64::try_from(q * s).map(Value::from).map_err(|_| overflow())
+		}
+		(Number::Decimal(_), _) | (_, Number::Decimal(_)) => {
+			let (v, s) = (arg.to_decimal(), step.to_decimal());
+			v.checked_div(s)
+				.map(|q| q.round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero))
+				.and_then(|q| q.checked_mul(s))
+				.map(Value::from)
+				.ok_or_else(overflow)
+		}
+		_ => {
+			let (v, s) = (arg.to_float(), step.to_float());
+			Ok(((v / s).round() * s).into())
+		}
+	}
+}
+
 pub fn rad2deg((arg,): (Number,)) -> Result<Value, Error> {
 	Ok(arg.rad2deg().into())
 }
 
+pub fn range_scale(
+	(arg, in_min, in_max, out_min, out_max, clamp): (
+		Number,
+		Number,
+		Number,
+		Number,
+		Number,
+		Option<bool>,
+	),
+) -> Result<Value, Error> {
+	let (in_min, in_max) = (in_min.to_float(), in_max.to_float());
+	if in_min == in_max {
+		return Err(Error::InvalidArguments {
+			name: String::from("math::range_scale"),
+			message: String::from("The input range must not be empty."),
+		});
+	}
+	let (out_min, out_max) = (out_min.to_float(), out_max.to_float());
+	let mut factor = (arg.to_float() - in_min) / (in_max - in_min);
+	// Optionally keep the output within the output range
+	if clamp.unwrap_or(false) {
+		factor = factor.clamp(0.0, 1.0);
+	}
+	Ok((out_min + (out_max - out_min) * factor).into())
+}
+
 pub fn round((arg,): (Number,)) -> Result<Value, Error> {
 	Ok(arg.round().into())
 }
 
+pub fn safe_div((arg, div, default): (Number, Number, Value)) -> Result<Value, Error> {
+	// Fall back to the default instead of dividing by zero
+	if div.is_zero() {
+		return Ok(default);
+	}
+	Ok(arg.try_div(div)?.into())
+}
+
+pub fn safe_mod((arg, div, default): (Number, Number, Value)) -> Result<Value, Error> {
+	// Fall back to the default instead of dividing by zero
+	if div.is_zero() {
+		return Ok(default);
+	}
+	Ok(arg.try_rem(div)?.into())
+}
+
 pub fn sign((arg,): (Number,)) -> Result<Value, Error> {
 	Ok(arg.sign().into())
 }
@@ -53,7 +53,18 @@ pub async fn run(
 		|| name.starts_with("crypto::bcrypt")
 		|| name.starts_with("crypto::pbkdf2")
 		|| name.starts_with("crypto::scrypt")
+		|| name.starts_with("array::count_where")
+		|| name.starts_with("array::flat_map")
+		|| name.starts_with("array::group_adjacent")
+		|| name.starts_with("array::is_sorted_by")
 		|| name.starts_with("array::map")
+		|| name.starts_with("array::max_by")
+		|| name.starts_with("array::min_by")
+		|| name.starts_with("array::ranked")
+		|| name.starts_with("array::reduce_right")
+		|| name.starts_with("array::running")
+		|| name.starts_with("array::to_object")
+		|| name.starts_with("object::filter")
 	{
 		stk.run(|stk| asynchronous(stk, ctx, opt, doc, name, args)).await
 	} else {
@@ -102,6 +113,7 @@ pub fn synchronous(
 		"array::any" => array::any,
 		"array::append" => array::append,
 		"array::at" => array::at,
+		"array::binary_search" => array::binary_search,
 		"array::boolean_and" => array::boolean_and,
 		"array::boolean_not" => array::boolean_not,
 		"array::boolean_or" => array::boolean_or,
@@ -116,13 +128,18 @@ pub fn synchronous(
 		"array::filter_index" => array::filter_index,
 		"array::find_index" => array::find_index,
 		"array::first" => array::first,
+		"array::first_n" => array::first_n,
 		"array::flatten" => array::flatten,
 		"array::group" => array::group,
+		"array::index_of" => array::index_of,
 		"array::insert" => array::insert,
 		"array::intersect" => array::intersect,
 		"array::is_empty" => array::is_empty,
+		"array::is_sorted" => array::is_sorted,
 		"array::join" => array::join,
 		"array::last" => array::last,
+		"array::last_index_of" => array::last_index_of,
+		"array::last_n" => array::last_n,
 		"array::len" => array::len,
 		"array::logical_and" => array::logical_and,
 		"array::logical_or" => array::logical_or,
@@ -130,6 +147,8 @@ pub fn synchronous(
 		"array::matches" => array::matches,
 		"array::max" => array::max,
 		"array::min" => array::min,
+		"array::pad" => array::pad,
+		"array::pad_start" => array::pad_start,
 		"array::pop" => array::pop,
 		"array::prepend" => array::prepend,
 		"array::push" => array::push,
@@ -140,11 +159,13 @@ pub fn synchronous(
 		"array::shuffle" => array::shuffle,
 		"array::slice" => array::slice,
 		"array::sort" => array::sort,
+		"array::splice" => array::splice,
 		"array::swap" => array::swap,
 		"array::transpose" => array::transpose,
 		"array::union" => array::union,
 		"array::sort::asc" => array::sort::asc,
 		"array::sort::desc" => array::sort::desc,
+		"array::weighted_sample" => array::weighted_sample,
 		"array::windows" => array::windows,
 		//
 		"bytes::len" => bytes::len,
@@ -152,10 +173,15 @@ pub fn synchronous(
 		"count" => count::count,
 		//
 		"crypto::blake3" => crypto::blake3,
+		"crypto::jwt::decode" => crypto::jwt::decode,
+		"crypto::decrypt" => crypto::decrypt,
+		"crypto::encrypt" => crypto::encrypt,
 		"crypto::md5" => crypto::md5,
 		"crypto::sha1" => crypto::sha1,
 		"crypto::sha256" => crypto::sha256,
 		"crypto::sha512" => crypto::sha512,
+		"crypto::totp::generate" => crypto::totp::generate(ctx),
+		"crypto::totp::verify" => crypto::totp::verify(ctx),
 		//
 		"duration::days" => duration::days,
 		"duration::hours" => duration::hours,
@@ -188,16 +214,25 @@ pub fn synchronous(
 		"math::abs" => math::abs,
 		"math::acos" => math::acos,
 		"math::acot" => math::acot,
+		"math::approx_eq" => math::approx_eq,
 		"math::asin" => math::asin,
 		"math::atan" => math::atan,
+		"math::binomial" => math::binomial,
 		"math::bottom" => math::bottom,
 		"math::ceil" => math::ceil,
 		"math::clamp" => math::clamp,
+		"math::copysign" => math::copysign,
 		"math::cos" => math::cos,
 		"math::cot" => math::cot,
 		"math::deg2rad" => math::deg2rad,
+		"math::entropy" => math::entropy,
+		"math::exponential_moving_average" => math::exponential_moving_average,
+		"math::factorial" => math::factorial,
 		"math::fixed" => math::fixed,
 		"math::floor" => math::floor,
+		"math::gini" => math::gini,
+		"math::hypot" => math::hypot,
+		"math::interpolate" => math::interpolate,
 		"math::interquartile" => math::interquartile,
 		"math::lerp" => math::lerp,
 		"math::lerpangle" => math::lerpangle,
@@ -211,12 +246,17 @@ pub fn synchronous(
 		"math::midhinge" => math::midhinge,
 		"math::min" => math::min,
 		"math::mode" => math::mode,
+		"math::moving_average" => math::moving_average,
 		"math::nearestrank" => math::nearestrank,
 		"math::percentile" => math::percentile,
 		"math::pow" => math::pow,
 		"math::product" => math::product,
+		"math::quantize" => math::quantize,
 		"math::rad2deg" => math::rad2deg,
+		"math::range_scale" => math::range_scale,
 		"math::round" => math::round,
+		"math::safe_div" => math::safe_div,
+		"math::safe_mod" => math::safe_mod,
 		"math::sign" => math::sign,
 		"math::sin" => math::sin,
 		"math::spread" => math::spread,
@@ -234,10 +274,18 @@ pub fn synchronous(
 		//
 		"not" => not::not,
 		//
+		"object::diff" => object::diff,
 		"object::entries" => object::entries,
+		"object::flatten" => object::flatten,
 		"object::from_entries" => object::from_entries,
+		"object::invert" => object::invert,
 		"object::keys" => object::keys,
 		"object::len" => object::len,
+		"object::merge_all" => object::merge_all,
+		"object::omit" => object::omit,
+		"object::pick" => object::pick,
+		"object::rename_keys" => object::rename_keys,
+		"object::unflatten" => object::unflatten,
 		"object::values" => object::values,
 		//
 		"parse::email::host" => parse::email::host,
@@ -272,20 +320,39 @@ pub fn synchronous(
 		"session::rd" => session::rd(ctx),
 		"session::token" => session::token(ctx),
 		//
+		"string::byte_len" => string::byte_len,
+		"string::camelcase" => string::camelcase,
+		"string::center" => string::center,
+		"string::char_len" => string::char_len,
 		"string::concat" => string::concat,
 		"string::contains" => string::contains,
+		"string::dedent" => string::dedent,
+		"string::diff" => string::diff,
 		"string::endsWith" => string::ends_with,
+		"string::format_number" => string::format_number,
+		"string::fuzzy_contains" => string::fuzzy_contains,
+		"string::grapheme_len" => string::grapheme_len,
+		"string::highlight" => string::highlight,
+		"string::indent" => string::indent,
 		"string::join" => string::join,
+		"string::kebabcase" => string::kebabcase,
 		"string::len" => string::len,
+		"string::lines" => string::lines,
 		"string::lowercase" => string::lowercase,
+		"string::mask" => string::mask,
 		"string::matches" => string::matches,
+		"string::pascalcase" => string::pascalcase,
 		"string::repeat" => string::repeat,
 		"string::replace" => string::replace,
 		"string::reverse" => string::reverse,
 		"string::slice" => string::slice,
 		"string::slug" => string::slug,
+		"string::snakecase" => string::snakecase,
 		"string::split" => string::split,
 		"string::startsWith" => string::starts_with,
+		"string::titlecase" => string::titlecase,
+		"string::to_ascii" => string::to_ascii,
+		"string::transliterate" => string::transliterate,
 		"string::trim" => string::trim,
 		"string::uppercase" => string::uppercase,
 		"string::words" => string::words,
@@ -324,8 +391,14 @@ pub fn synchronous(
 		"string::semver::set::minor" => string::semver::set::minor,
 		"string::semver::set::patch" => string::semver::set::patch,
 		//
+		"time::age" => time::age(ctx),
+		"time::age_at" => time::age_at,
+		"time::bucket" => time::bucket,
+		"time::bucket_key" => time::bucket_key,
 		"time::ceil" => time::ceil,
 		"time::day" => time::day,
+		"time::diff" => time::diff,
+		"time::end_of" => time::end_of,
 		"time::floor" => time::floor,
 		"time::format" => time::format,
 		"time::group" => time::group,
@@ -337,9 +410,11 @@ pub fn synchronous(
 		"time::nano" => time::nano,
 		"time::micros" => time::micros,
 		"time::millis" => time::millis,
-		"time::now" => time::now,
+		"time::now" => time::now(ctx),
+		"time::now_monotonic" => time::now_monotonic(ctx),
 		"time::round" => time::round,
 		"time::second" => time::second,
+		"time::start_of" => time::start_of,
 		"time::timezone" => time::timezone,
 		"time::unix" => time::unix,
 		"time::wday" => time::wday,
@@ -351,15 +426,24 @@ pub fn synchronous(
 		"time::from::millis" => time::from::millis,
 		"time::from::secs" => time::from::secs,
 		"time::from::unix" => time::from::unix,
+		"time::timezone::convert" => time::timezone::convert,
+		"time::timezone::offset" => time::timezone::offset,
 		//
+		"type::assert" => r#type::assert,
 		"type::bool" => r#type::bool,
 		"type::bytes" => r#type::bytes,
+		"type::cast_array" => r#type::cast_array,
+		"type::coerce" => r#type::coerce,
+		"type::convert" => r#type::convert,
 		"type::datetime" => r#type::datetime,
 		"type::decimal" => r#type::decimal,
+		"type::depth" => r#type::depth,
 		"type::duration" => r#type::duration,
+		"type::enum" => r#type::r#enum,
 		"type::float" => r#type::float,
 		"type::geometry" => r#type::geometry,
 		"type::int" => r#type::int,
+		"type::kind_of" => r#type::kind_of,
 		"type::number" => r#type::number,
 		"type::point" => r#type::point,
 		"type::range" => r#type::range,
@@ -439,6 +523,7 @@ pub async fn idiom(
 				"any" => array::any,
 				"append" => array::append,
 				"at" => array::at,
+				"binary_search" => array::binary_search,
 				"boolean_and" => array::boolean_and,
 				"boolean_not" => array::boolean_not,
 				"boolean_or" => array::boolean_or,
@@ -447,19 +532,28 @@ pub async fn idiom(
 				"combine" => array::combine,
 				"complement" => array::complement,
 				"concat" => array::concat,
+				"count_where" => array::count_where((stk, ctx, opt, doc)).await,
 				"difference" => array::difference,
 				"distinct" => array::distinct,
 				"fill" => array::fill,
 				"filter_index" => array::filter_index,
 				"find_index" => array::find_index,
 				"first" => array::first,
+				"first_n" => array::first_n,
+				"flat_map" => array::flat_map((stk, ctx, opt, doc)).await,
 				"flatten" => array::flatten,
 				"group" => array::group,
+				"group_adjacent" => array::group_adjacent((stk, ctx, opt, doc)).await,
+				"index_of" => array::index_of,
 				"insert" => array::insert,
 				"intersect" => array::intersect,
 				"is_empty" => array::is_empty,
+				"is_sorted" => array::is_sorted,
+				"is_sorted_by" => array::is_sorted_by((stk, ctx, opt, doc)).await,
 				"join" => array::join,
 				"last" => array::last,
+				"last_index_of" => array::last_index_of,
+				"last_n" => array::last_n,
 				"len" => array::len,
 				"logical_and" => array::logical_and,
 				"logical_or" => array::logical_or,
@@ -467,20 +561,27 @@ pub async fn idiom(
 				"matches" => array::matches,
 				"map" => array::map((stk, ctx, opt, doc)).await,
 				"max" => array::max,
+				"max_by" => array::max_by((stk, ctx, opt, doc)).await,
 				"min" => array::min,
+				"min_by" => array::min_by((stk, ctx, opt, doc)).await,
 				"pop" => array::pop,
 				"prepend" => array::prepend,
 				"push" => array::push,
+				"ranked" => array::ranked((stk, ctx, opt, doc)).await,
+				"reduce_right" => array::reduce_right((stk, ctx, opt, doc)).await,
 				"remove" => array::remove,
 				"reverse" => array::reverse,
+				"running" => array::running((stk, ctx, opt, doc)).await,
 				"shuffle" => array::shuffle,
 				"slice" => array::slice,
 				"sort" => array::sort,
 				"swap" => array::swap,
+				"to_object" => array::to_object((stk, ctx, opt, doc)).await,
 				"transpose" => array::transpose,
 				"union" => array::union,
 				"sort_asc" => array::sort::asc,
 				"sort_desc" => array::sort::desc,
+				"weighted_sample" => array::weighted_sample,
 				"windows" => array::windows,
 				//
 				"vector_add" => vector::add,
@@ -559,9 +660,17 @@ pub async fn idiom(
 				name,
 				args.clone(),
 				"no such method found for the object type",
+				"diff" => object::diff,
 				"entries" => object::entries,
+				"filter" => object::filter((stk, ctx, opt, doc)).await,
+				"flatten" => object::flatten,
+				"invert" => object::invert,
 				"keys" => object::keys,
 				"len" => object::len,
+				"omit" => object::omit,
+				"pick" => object::pick,
+				"rename_keys" => object::rename_keys,
+				"unflatten" => object::unflatten,
 				"values" => object::values,
 			)
 		}
@@ -570,12 +679,21 @@ pub async fn idiom(
 				name,
 				args.clone(),
 				"no such method found for the string type",
+				"byte_len" => string::byte_len,
+				"center" => string::center,
+				"char_len" => string::char_len,
 				"concat" => string::concat,
 				"contains" => string::contains,
+				"diff" => string::diff,
 				"endsWith" => string::ends_with,
+				"fuzzy_contains" => string::fuzzy_contains,
+				"grapheme_len" => string::grapheme_len,
+				"highlight" => string::highlight,
 				"join" => string::join,
 				"len" => string::len,
+				"lines" => string::lines,
 				"lowercase" => string::lowercase,
+				"mask" => string::mask,
 				"matches" => string::matches,
 				"repeat" => string::repeat,
 				"replace" => string::replace,
@@ -584,6 +702,8 @@ pub async fn idiom(
 				"slug" => string::slug,
 				"split" => string::split,
 				"startsWith" => string::starts_with,
+				"to_ascii" => string::to_ascii,
+				"transliterate" => string::transliterate,
 				"trim" => string::trim,
 				"uppercase" => string::uppercase,
 				"words" => string::words,
@@ -628,8 +748,13 @@ pub async fn idiom(
 				name,
 				args.clone(),
 				"no such method found for the datetime type",
+				"time_age" => time::age(ctx),
+				"time_age_at" => time::age_at,
+				"time_bucket" => time::bucket,
+				"time_bucket_key" => time::bucket_key,
 				"time_ceil" => time::ceil,
 				"time_day" => time::day,
+				"time_end_of" => time::end_of,
 				"time_floor" => time::floor,
 				"time_format" => time::format,
 				"time_group" => time::group,
@@ -641,6 +766,7 @@ pub async fn idiom(
 				"time_millis" => time::millis,
 				"time_round" => time::round,
 				"time_second" => time::second,
+				"time_start_of" => time::start_of,
 				"time_unix" => time::unix,
 				"time_wday" => time::wday,
 				"time_week" => time::week,
@@ -739,13 +865,24 @@ pub async fn asynchronous(
 		name,
 		args,
 		"no such builtin function found",
+		"array::count_where" => array::count_where((stk, ctx, opt, doc)).await,
+		"array::flat_map" => array::flat_map((stk, ctx, opt, doc)).await,
+		"array::group_adjacent" => array::group_adjacent((stk, ctx, opt, doc)).await,
+		"array::is_sorted_by" => array::is_sorted_by((stk, ctx, opt, doc)).await,
 		"array::map" => array::map((stk, ctx, opt, doc)).await,
+		"array::max_by" => array::max_by((stk, ctx, opt, doc)).await,
+		"array::min_by" => array::min_by((stk, ctx, opt, doc)).await,
+		"array::ranked" => array::ranked((stk, ctx, opt, doc)).await,
+		"array::reduce_right" => array::reduce_right((stk, ctx, opt, doc)).await,
+		"array::running" => array::running((stk, ctx, opt, doc)).await,
+		"array::to_object" => array::to_object((stk, ctx, opt, doc)).await,
 		//
 		"crypto::argon2::compare" => (cpu_intensive) crypto::argon2::cmp.await,
 		"crypto::argon2::generate" => (cpu_intensive) crypto::argon2::gen.await,
 		"crypto::bcrypt::compare" => (cpu_intensive) crypto::bcrypt::cmp.await,
 		"crypto::bcrypt::generate" => (cpu_intensive) crypto::bcrypt::gen.await,
 		"crypto::pbkdf2::compare" => (cpu_intensive) crypto::pbkdf2::cmp.await,
+		"crypto::pbkdf2::derive" => (cpu_intensive) crypto::pbkdf2::derive.await,
 		"crypto::pbkdf2::generate" => (cpu_intensive) crypto::pbkdf2::gen.await,
 		"crypto::scrypt::compare" => (cpu_intensive) crypto::scrypt::cmp.await,
 		"crypto::scrypt::generate" => (cpu_intensive) crypto::scrypt::gen.await,
@@ -757,6 +894,8 @@ pub async fn asynchronous(
 		"http::patch" => http::patch(ctx).await,
 		"http::delete" => http::delete(ctx).await,
 		//
+		"object::filter" => object::filter((stk, ctx, opt, doc)).await,
+		//
 		"search::analyze" => search::analyze((stk,ctx, Some(opt))).await,
 		"search::score" => search::score((ctx, doc)).await,
 		"search::highlight" => search::highlight((ctx, doc)).await,
@@ -796,7 +935,20 @@ mod tests {
 	#[tokio::test]
 	async fn implementations_are_present() {
 		#[cfg(all(feature = "scripting", feature = "kv-mem"))]
-		let excluded_from_scripting = &["array::map"];
+		let excluded_from_scripting = &[
+			"array::count_where",
+			"array::flat_map",
+			"array::group_adjacent",
+			"array::is_sorted_by",
+			"array::map",
+			"array::max_by",
+			"array::min_by",
+			"array::ranked",
+			"array::reduce_right",
+			"array::running",
+			"array::to_object",
+			"object::filter",
+		];
 
 		// Accumulate and display all problems at once to avoid a test -> fix -> test -> fix cycle.
 		let mut problems = Vec::new();
@@ -1,8 +1,55 @@
 use std::collections::BTreeMap;
+use std::mem::size_of;
 
+use reblessive::tree::Stk;
+
+use crate::cnf::FUNCTION_ALLOCATION_LIMIT;
+use crate::ctx::Context;
+use crate::dbs::Options;
+use crate::doc::CursorDoc;
 use crate::err::Error;
 use crate::sql::value::Value;
-use crate::sql::{Array, Object, Strand};
+use crate::sql::{Array, Closure, Function, Object, Part, Strand};
+
+pub fn diff((a, b, deep): (Object, Object, Option<bool>)) -> Result<Value, Error> {
+	#[derive(Default)]
+	struct Changes {
+		added: BTreeMap<String, Value>,
+		removed: BTreeMap<String, Value>,
+		changed: BTreeMap<String, Value>,
+	}
+	fn walk(prefix: &str, a: Object, mut b: Object, deep: bool, out: &mut Changes) {
+		let key = |k: String| match prefix.is_empty() {
+			true => k,
+			false => format!("{prefix}.{k}"),
+		};
+		for (k, old) in a.0 {
+			match (old, b.0.remove(&k)) {
+				(old, None) => {
+					out.removed.insert(key(k), old);
+				}
+				(old, Some(new)) if old == new => {}
+				// Nested objects are compared key by key, using dotted keys
+				(Value::Object(old), Some(Value::Object(new))) if deep => {
+					walk(&key(k), old, new, deep, out);
+				}
+				(old, Some(new)) => {
+					out.changed.insert(key(k), Value::from(vec![old, new]));
+				}
+			}
+		}
+		for (k, new) in b.0 {
+			out.added.insert(key(k), new);
+		}
+	}
+	let mut out = Changes::default();
+	walk("", a, b, deep.unwrap_or(false), &mut out);
+	Ok(Value::from(map! {
+		String::from("added") => Value::from(out.added),
+		String::from("removed") => Value::from(out.removed),
+		String::from("changed") => Value::from(out.changed),
+	}))
+}
 
 pub fn entries((object,): (Object,)) -> Result<Value, Error> {
 	Ok(Value::Array(Array(
@@ -15,6 +62,58 @@ pub fn entries((object,): (Object,)) -> Result<Value, Error> {
 	)))
 }
 
+pub async fn filter(
+	(stk, ctx, opt, doc): (&mut Stk, &Context, &Options, Option<&CursorDoc>),
+	(object, predicate): (Object, Closure),
+) -> Result<Value, Error> {
+	// The predicate is called with both the key and the value
+	if predicate.args.len() != 2 {
+		return Err(Error::InvalidArguments {
+			name: String::from("object::filter"),
+			message: String::from("Expected a closure with two arguments, the key and the value"),
+		});
+	}
+	let mut obj = BTreeMap::new();
+	for (k, v) in object.0 {
+		let args = vec![Value::from(k.as_str()), v.clone()];
+		let fnc = Function::Anonymous(predicate.clone().into(), args);
+		if fnc.compute(stk, ctx, opt, doc).await?.is_truthy() {
+			obj.insert(k, v);
+		}
+	}
+	Ok(Value::Object(Object(obj)))
+}
+
+pub fn flatten((object,): (Object,)) -> Result<Value, Error> {
+	fn walk(prefix: String, value: Value, out: &mut BTreeMap<String, Value>) {
+		match value {
+			Value::Object(v) if !v.is_empty() => {
+				for (k, v) in v.0 {
+					let key = match prefix.is_empty() {
+						true => k,
+						false => format!("{prefix}.{k}"),
+					};
+					walk(key, v, out);
+				}
+			}
+			Value::Array(v) if !v.is_empty() && !prefix.is_empty() => {
+				for (i, v) in v.0.into_iter().enumerate() {
+					walk(format!("{prefix}[{i}]"), v, out);
+				}
+			}
+			// Scalars and empty objects or arrays are kept as they are
+			v => {
+				out.insert(prefix, v);
+			}
+		}
+	}
+	let mut out = BTreeMap::new();
+	for (k, v) in object.0 {
+		walk(k, v, &mut out);
+	}
+	Ok(Value::Object(Object(out)))
+}
+
 pub fn from_entries((array,): (Array,)) -> Result<Value, Error> {
 	let mut obj: BTreeMap<String, Value> = BTreeMap::default();
 
@@ -58,6 +157,37 @@ pub fn from_entries((array,): (Array,)) -> Result<Value, Error> {
 	Ok(Value::Object(Object(obj)))
 }
 
+pub fn invert((object, last): (Object, Option<bool>)) -> Result<Value, Error> {
+	let invalid = |message: String| Error::InvalidArguments {
+		name: String::from("object::invert"),
+		message,
+	};
+	let mut out = Object::default();
+	for (k, v) in object {
+		let key = match v {
+			Value::Strand(s) => s.0,
+			v @ (Value::Number(_)
+			| Value::Bool(_)
+			| Value::Uuid(_)
+			| Value::Datetime(_)
+			| Value::Duration(_)
+			| Value::Thing(_)) => v.as_raw_string(),
+			v => {
+				return Err(invalid(format!(
+					"Expected the value of key '{k}' to be convertible to a string but found {}.",
+					v.kindof()
+				)))
+			}
+		};
+		// Duplicate values are an error, unless the last key should be kept
+		if !last.unwrap_or(false) && out.contains_key(&key) {
+			return Err(invalid(format!("The value '{key}' appears more than once.")));
+		}
+		out.insert(key, Value::from(k));
+	}
+	Ok(out.into())
+}
+
 pub fn len((object,): (Object,)) -> Result<Value, Error> {
 	Ok(Value::from(object.len()))
 }
@@ -66,6 +196,169 @@ pub fn keys((object,): (Object,)) -> Result<Value, Error> {
 	Ok(Value::Array(Array(object.keys().map(|v| Value::Strand(Strand(v.to_owned()))).collect())))
 }
 
+pub fn merge_all((array, deep): (Array, Option<bool>)) -> Result<Value, Error> {
+	let mut out = Value::Object(Object::default());
+	for v in array {
+		match v {
+			// Nested objects are merged recursively
+			v @ Value::Object(_) if deep.unwrap_or(false) => out.merge(v)?,
+			// Otherwise later keys replace earlier ones
+			Value::Object(o) => {
+				for (k, v) in o {
+					out.put(&[Part::from(k)], v);
+				}
+			}
+			v => {
+				return Err(Error::InvalidArguments {
+					name: String::from("object::merge_all"),
+					message: format!("Expected an array of objects but found {}.", v.kindof()),
+				})
+			}
+		}
+	}
+	Ok(out)
+}
+
+pub fn omit((object, keys): (Object, Vec<String>)) -> Result<Value, Error> {
+	let mut obj = Value::Object(object);
+	for key in keys {
+		obj.cut(&path(&key));
+	}
+	Ok(obj)
+}
+
+pub fn pick((object, keys): (Object, Vec<String>)) -> Result<Value, Error> {
+	let obj = Value::Object(object);
+	let mut out = Value::Object(Object::default());
+	for key in keys {
+		let path = path(&key);
+		// Only keys which exist in the object are picked
+		match obj.pick(&path) {
+			Value::None => {}
+			v => out.put(&path, v),
+		}
+	}
+	Ok(out)
+}
+
+pub fn rename_keys((object, mapping): (Object, Object)) -> Result<Value, Error> {
+	let invalid = |message: String| Error::InvalidArguments {
+		name: String::from("object::rename_keys"),
+		message,
+	};
+	let mut out = Object::default();
+	for (k, v) in object {
+		// Keys which are not in the mapping are left unchanged
+		let key = match mapping.get(&k) {
+			Some(Value::Strand(s)) => s.0.clone(),
+			Some(v) => {
+				return Err(invalid(format!(
+					"Expected the new name for key '{k}' to be a string but found {}.",
+					v.kindof()
+				)))
+			}
+			None => k,
+		};
+		if out.contains_key(&key) {
+			return Err(invalid(format!("The key '{key}' would be renamed onto an existing key.")));
+		}
+		out.insert(key, v);
+	}
+	Ok(out.into())
+}
+
+pub fn unflatten((object,): (Object,)) -> Result<Value, Error> {
+	let mut out = Value::Object(Object::default());
+	for (key, v) in object.0 {
+		let mut target = &mut out;
+		for segment in segments(&key) {
+			// Create the container this segment refers to if it is missing
+			if target.is_none() {
+				*target = match segment {
+					Segment::Field(_) => Value::Object(Object::default()),
+					Segment::Index(_) => Value::Array(Array::default()),
+				};
+			}
+			target = match (target, segment) {
+				(Value::Object(o), Segment::Field(f)) => {
+					o.entry(f.to_owned()).or_insert(Value::None)
+				}
+				(Value::Array(a), Segment::Index(i)) => {
+					if a.len() <= i {
+						limit(size_of::<Value>().saturating_mul(i + 1))?;
+						a.resize(i + 1, Value::None);
+					}
+					&mut a[i]
+				}
+				_ => return Err(conflict(&key)),
+			};
+		}
+		if !target.is_none() {
+			return Err(conflict(&key));
+		}
+		*target = v;
+	}
+	Ok(out)
+}
+
 pub fn values((object,): (Object,)) -> Result<Value, Error> {
 	Ok(Value::Array(Array(object.values().map(|v| v.to_owned()).collect())))
 }
+
+/// Splits a dotted key, such as `a.b`, into the nested fields it refers to
+fn path(key: &str) -> Vec<Part> {
+	key.split('.').map(Part::from).collect()
+}
+
+/// Returns an error if an array of this size is too much to allocate.
+fn limit(n: usize) -> Result<(), Error> {
+	if n > *FUNCTION_ALLOCATION_LIMIT {
+		Err(Error::InvalidArguments {
+			name: String::from("object::unflatten"),
+			message: format!("Output must not exceed {} bytes.", *FUNCTION_ALLOCATION_LIMIT),
+		})
+	} else {
+		Ok(())
+	}
+}
+
+/// A single step in a flattened key, such as `a` or `[0]` in `a[0].b`
+#[derive(Clone, Copy)]
+enum Segment<'a> {
+	Field(&'a str),
+	Index(usize),
+}
+
+/// Splits a flattened key, such as `a[0].b`, into the fields and indexes it refers to
+fn segments(key: &str) -> Vec<Segment<'_>> {
+	let mut out = Vec::new();
+	for part in key.split('.') {
+		match part.find('[').and_then(|i| Some((&part[..i], indexes(&part[i..])?))) {
+			Some((field, idx)) if !field.is_empty() => {
+				out.push(Segment::Field(field));
+				out.extend(idx.into_iter().map(Segment::Index));
+			}
+			// Fields which don't end in valid indexes are kept as they are
+			_ => out.push(Segment::Field(part)),
+		}
+	}
+	out
+}
+
+/// Parses a sequence of bracketed indexes, such as `[0][1]`
+fn indexes(mut text: &str) -> Option<Vec<usize>> {
+	let mut out = Vec::new();
+	while !text.is_empty() {
+		let (idx, rest) = text.strip_prefix('[')?.split_once(']')?;
+		out.push(idx.parse().ok()?);
+		text = rest;
+	}
+	Some(out)
+}
+
+fn conflict(key: &str) -> Error {
+	Error::InvalidArguments {
+		name: String::from("object::unflatten"),
+		message: format!("The key '{key}' conflicts with another key in the object."),
+	}
+}
@@ -13,6 +13,7 @@ impl_module_def!(
 	"any" => run,
 	"at" => run,
 	"append" => run,
+	"binary_search" => run,
 	"boolean_and" => run,
 	"boolean_not" => run,
 	"boolean_or" => run,
@@ -27,14 +28,19 @@ impl_module_def!(
 	"filter_index" => run,
 	"find_index" => run,
 	"first" => run,
+	"first_n" => run,
 	"flatten" => run,
 	"group" => run,
+	"index_of" => run,
 	"insert" => run,
 	"intersect" => run,
 	"is_empty" => run,
+	"is_sorted" => run,
 	"join" => run,
 	"knn" => run,
 	"last" => run,
+	"last_index_of" => run,
+	"last_n" => run,
 	"len" => run,
 	"logical_and" => run,
 	"logical_or" => run,
@@ -42,6 +48,8 @@ impl_module_def!(
 	"matches" => run,
 	"max" => run,
 	"min" => run,
+	"pad" => run,
+	"pad_start" => run,
 	"pop" => run,
 	"push" => run,
 	"prepend" => run,
@@ -52,8 +60,10 @@ impl_module_def!(
 	"shuffle" => run,
 	"slice" => run,
 	"sort" => (sort::Package),
+	"splice" => run,
 	"swap" => run,
 	"transpose" => run,
 	"union" => run,
+	"weighted_sample" => run,
 	"windows" => run
 );
@@ -3,8 +3,10 @@ use crate::fnc::script::modules::impl_module_def;
 
 mod argon2;
 mod bcrypt;
+mod jwt;
 mod pbkdf2;
 mod scrypt;
+mod totp;
 
 #[non_exhaustive]
 pub struct Package;
@@ -13,12 +15,16 @@ impl_module_def!(
 	Package,
 	"crypto",
 	"blake3" => run,
+	"decrypt" => run,
+	"encrypt" => run,
 	"md5" => run,
 	"sha1" => run,
 	"sha256" => run,
 	"sha512" => run,
 	"argon2" => (argon2::Package),
 	"bcrypt" => (bcrypt::Package),
+	"jwt" => (jwt::Package),
 	"pbkdf2" => (pbkdf2::Package),
-	"scrypt" => (scrypt::Package)
+	"scrypt" => (scrypt::Package),
+	"totp" => (totp::Package)
 );
@@ -9,5 +9,6 @@ impl_module_def!(
 	Package,
 	"crypto::pbkdf2",
 	"compare" => fut Async,
+	"derive" => fut Async,
 	"generate" => fut Async
 );
@@ -10,16 +10,25 @@ impl_module_def!(
 	"abs" => run,
 	"acos" => run,
 	"acot" => run,
+	"approx_eq" => run,
 	"asin" => run,
 	"atan" => run,
+	"binomial" => run,
 	"bottom" => run,
 	"ceil" => run,
 	"clamp" => run,
+	"copysign" => run,
 	"cos" => run,
 	"cot" => run,
 	"deg2rad" => run,
+	"entropy" => run,
+	"exponential_moving_average" => run,
+	"factorial" => run,
 	"fixed" => run,
 	"floor" => run,
+	"gini" => run,
+	"hypot" => run,
+	"interpolate" => run,
 	"interquartile" => run,
 	"lerp" => run,
 	"lerpangle" => run,
@@ -33,12 +42,17 @@ impl_module_def!(
 	"midhinge" => run,
 	"min" => run,
 	"mode" => run,
+	"moving_average" => run,
 	"nearestrank" => run,
 	"percentile" => run,
 	"pow" => run,
 	"product" => run,
+	"quantize" => run,
 	"rad2deg" => run,
+	"range_scale" => run,
 	"round" => run,
+	"safe_div" => run,
+	"safe_mod" => run,
 	"sign" => run,
 	"sin" => run,
 	"spread" => run,
@@ -7,9 +7,17 @@ pub struct Package;
 impl_module_def!(
 	Package,
 	"object",
+	"diff" => run,
 	"entries" => run,
+	"flatten" => run,
 	"from_entries" => run,
+	"invert" => run,
 	"keys" => run,
 	"len" => run,
+	"merge_all" => run,
+	"omit" => run,
+	"pick" => run,
+	"rename_keys" => run,
+	"unflatten" => run,
 	"values" => run
 );
@@ -13,24 +13,43 @@ pub struct Package;
 impl_module_def!(
 	Package,
 	"string",
+	"byte_len" => run,
+	"camelcase" => run,
+	"center" => run,
+	"char_len" => run,
 	"concat" => run,
 	"contains" => run,
 	"distance" => (distance::Package),
+	"dedent" => run,
+	"diff" => run,
 	"endsWith" => run,
 	"html" => (html::Package),
 	"is" => (is::Package),
+	"format_number" => run,
+	"fuzzy_contains" => run,
+	"grapheme_len" => run,
+	"highlight" => run,
+	"indent" => run,
 	"join" => run,
+	"kebabcase" => run,
 	"len" => run,
+	"lines" => run,
 	"lowercase" => run,
+	"mask" => run,
 	"matches" => run,
+	"pascalcase" => run,
 	"repeat" => run,
 	"replace" => run,
 	"reverse" => run,
 	"similarity" => (similarity::Package),
 	"slice" => run,
 	"slug" => run,
+	"snakecase" => run,
 	"split" => run,
 	"startsWith" => run,
+	"titlecase" => run,
+	"to_ascii" => run,
+	"transliterate" => run,
 	"trim" => run,
 	"uppercase" => run,
 	"words" => run,
@@ -2,6 +2,7 @@ use super::run;
 use crate::fnc::script::modules::impl_module_def;
 
 mod from;
+mod timezone;
 
 #[non_exhaustive]
 pub struct Package;
@@ -9,8 +10,14 @@ pub struct Package;
 impl_module_def!(
 	Package,
 	"time",
+	"age" => run,
+	"age_at" => run,
+	"bucket" => run,
+	"bucket_key" => run,
 	"ceil" => run,
 	"day" => run,
+	"diff" => run,
+	"end_of" => run,
 	"floor" => run,
 	"format" => run,
 	"group" => run,
@@ -24,10 +31,12 @@ impl_module_def!(
 	"micros" => run,
 	"millis" => run,
 	"now" => run,
+	"now_monotonic" => run,
 	"round" => run,
 	"second" => run,
 	"secs" => run,
-	"timezone" => run,
+	"start_of" => run,
+	"timezone" => (timezone::Package),
 	"unix" => run,
 	"wday" => run,
 	"week" => run,
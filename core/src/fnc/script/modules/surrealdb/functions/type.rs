@@ -11,16 +11,23 @@ pub struct Package;
 impl_module_def!(
 	Package,
 	"type",
+	"assert" => run,
 	"bool" => run,
 	"bytes" => run,
+	"cast_array" => run,
+	"coerce" => run,
+	"convert" => run,
 	"datetime" => run,
 	"decimal" => run,
+	"depth" => run,
 	"duration" => run,
 	"field" => fut Async,
 	"fields" => fut Async,
+	"enum" => run,
 	"float" => run,
 	"int" => run,
 	"is" => (is::Package),
+	"kind_of" => run,
 	"number" => run,
 	"point" => run,
 	"regex" => run,
@@ -2,7 +2,11 @@ use crate::cnf::FUNCTION_ALLOCATION_LIMIT;
 use crate::err::Error;
 use crate::fnc::util::string;
 use crate::sql::value::Value;
-use crate::sql::Regex;
+use crate::sql::{Number, Object, Regex};
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::{Decimal, RoundingStrategy};
+use std::iter::once;
+use unicode_segmentation::UnicodeSegmentation;
 
 /// Returns `true` if a string of this length is too much to allocate.
 fn limit(name: &str, n: usize) -> Result<(), Error> {
@@ -16,6 +20,33 @@ fn limit(name: &str, n: usize) -> Result<(), Error> {
 	}
 }
 
+pub fn byte_len((string,): (String,)) -> Result<Value, Error> {
+	Ok(Value::from(string.len()))
+}
+
+pub fn camelcase((string,): (String,)) -> Result<Value, Error> {
+	Ok(string::case::camelcase(&string).into())
+}
+
+pub fn center((string, width, fill): (String, usize, Option<String>)) -> Result<Value, Error> {
+	let mut fill = fill.as_deref().unwrap_or(" ").graphemes(true);
+	let (Some(fill), None) = (fill.next(), fill.next()) else {
+		return Err(Error::InvalidArguments {
+			name: String::from("string::center"),
+			message: String::from("The fill must be a single character."),
+		});
+	};
+	let pad = width.saturating_sub(string.graphemes(true).count());
+	limit("string::center", pad.saturating_mul(fill.len()).saturating_add(string.len()))?;
+	// Any uneven padding goes on the right
+	let left = pad / 2;
+	Ok(format!("{}{string}{}", fill.repeat(left), fill.repeat(pad - left)).into())
+}
+
+pub fn char_len((string,): (String,)) -> Result<Value, Error> {
+	Ok(Value::from(string.chars().count()))
+}
+
 pub fn concat(args: Vec<Value>) -> Result<Value, Error> {
 	let strings = args.into_iter().map(Value::as_string).collect::<Vec<_>>();
 	limit("string::concat", strings.iter().map(String::len).sum::<usize>())?;
@@ -26,11 +57,174 @@ pub fn contains((val, check): (String, String)) -> Result<Value, Error> {
 	Ok(val.contains(&check).into())
 }
 
+pub fn dedent((string,): (String,)) -> Result<Value, Error> {
+	// Find the whitespace shared by all of the lines which are not blank
+	let indent = string
+		.split('\n')
+		.filter(|l| !l.trim().is_empty())
+		.map(|l| l.len() - l.trim_start_matches([' ', '\t']).len())
+		.min()
+		.unwrap_or(0);
+	Ok(string
+		.split('\n')
+		.map(|l| match l.trim().is_empty() {
+			true => l.get(indent..).unwrap_or_default(),
+			false => &l[indent..],
+		})
+		.collect::<Vec<_>>()
+		.join("\n")
+		.into())
+}
+
+pub fn diff((a, b, granularity): (String, String, Option<String>)) -> Result<Value, Error> {
+	let split = match granularity.as_deref().unwrap_or("word") {
+		"line" => string::diff::lines,
+		"word" => string::diff::words,
+		"char" => string::diff::chars,
+		_ => {
+			return Err(Error::InvalidArguments {
+				name: String::from("string::diff"),
+				message: String::from(
+					"The granularity must be one of \"line\", \"word\", or \"char\".",
+				),
+			})
+		}
+	};
+	let (a, b) = (split(&a), split(&b));
+	limit("string::diff", string::diff::cells(&a, &b).saturating_mul(4))?;
+	Ok(string::diff::diff(&a, &b)
+		.into_iter()
+		.map(|(op, text)| {
+			Value::from(map! {
+				String::from("op") => Value::from(op.as_str()),
+				String::from("text") => Value::from(text),
+			})
+		})
+		.collect::<Vec<_>>()
+		.into())
+}
+
 pub fn ends_with((val, chr): (String, String)) -> Result<Value, Error> {
 	Ok(val.ends_with(&chr).into())
 }
 
+pub fn format_number((number, options): (Number, Option<Object>)) -> Result<Value, Error> {
+	let invalid = |message: &str| Error::InvalidArguments {
+		name: String::from("string::format_number"),
+		message: message.to_owned(),
+	};
+	// Decimals are used so that floats are formatted without rounding errors
+	let number = match number {
+		Number::Float(v) => {
+			Decimal::from_f64(v).ok_or_else(|| invalid("The number must be finite."))?
+		}
+		v => v.to_decimal(),
+	};
+	let (mut thousands, mut decimal, mut precision) = (String::new(), String::from("."), None);
+	for (k, v) in options.unwrap_or_default() {
+		match (k.as_str(), v) {
+			("thousands", Value::Strand(v)) => thousands = v.0,
+			("decimal", Value::Strand(v)) => decimal = v.0,
+			("precision", Value::Number(Number::Int(v))) if (0..=28).contains(&v) => {
+				precision = Some(v as u32)
+			}
+			_ => return Err(invalid(&format!("The option '{k}' is not valid."))),
+		}
+	}
+	let number = match precision {
+		Some(p) => {
+			let mut v = number.round_dp_with_strategy(p, RoundingStrategy::MidpointAwayFromZero);
+			v.rescale(p);
+			v
+		}
+		None => number,
+	};
+	let digits = number.abs().to_string();
+	let (int, frac) = digits.split_once('.').unwrap_or((&digits, ""));
+	let mut out = String::new();
+	if number.is_sign_negative() && !number.is_zero() {
+		out.push('-');
+	}
+	// Group the whole part of the number in threes
+	for (i, c) in int.chars().enumerate() {
+		if i > 0 && (int.len() - i) % 3 == 0 {
+			out.push_str(&thousands);
+		}
+		out.push(c);
+	}
+	if !frac.is_empty() {
+		out.push_str(&decimal);
+		out.push_str(frac);
+	}
+	Ok(out.into())
+}
+
+pub fn fuzzy_contains(
+	(haystack, needle, threshold): (String, String, f64),
+) -> Result<Value, Error> {
+	if !(0.0..=1.0).contains(&threshold) {
+		return Err(Error::InvalidArguments {
+			name: String::from("string::fuzzy_contains"),
+			message: String::from("The threshold must be a number between 0 and 1."),
+		});
+	}
+	let needle: Vec<char> = needle.to_lowercase().chars().collect();
+	if needle.is_empty() {
+		return Ok(Value::Bool(true));
+	}
+	let haystack: Vec<char> = haystack.to_lowercase().chars().collect();
+	// Compare the needle against the closest matching substring of the haystack
+	let distance = distance::levenshtein_substring(&needle, &haystack);
+	let similarity = 1.0 - distance as f64 / needle.len() as f64;
+	Ok(Value::Bool(similarity >= threshold))
+}
+
+pub fn grapheme_len((string,): (String,)) -> Result<Value, Error> {
+	Ok(Value::from(string.graphemes(true).count()))
+}
+
+pub fn highlight(
+	(string, terms, prefix, suffix): (String, Vec<String>, String, String),
+) -> Result<Value, Error> {
+	let ranges = string::highlight::ranges(&string, &terms);
+	let markers = ranges.len().saturating_mul(prefix.len().saturating_add(suffix.len()));
+	limit("string::highlight", markers.saturating_add(string.len()))?;
+	let mut out = String::with_capacity(markers + string.len());
+	let mut last = 0;
+	for r in ranges {
+		out.push_str(&string[last..r.start]);
+		out.push_str(&prefix);
+		out.push_str(&string[r.clone()]);
+		out.push_str(&suffix);
+		last = r.end;
+	}
+	out.push_str(&string[last..]);
+	Ok(out.into())
+}
+
+pub fn indent((string, num): (String, usize)) -> Result<Value, Error> {
+	let lines = string.split('\n').count();
+	limit("string::indent", num.saturating_mul(lines).saturating_add(string.len()))?;
+	let prefix = " ".repeat(num);
+	// Blank lines are left as they are
+	Ok(string
+		.split('\n')
+		.map(|l| match l.trim().is_empty() {
+			true => l.to_owned(),
+			false => format!("{prefix}{l}"),
+		})
+		.collect::<Vec<_>>()
+		.join("\n")
+		.into())
+}
+
 pub fn join(args: Vec<Value>) -> Result<Value, Error> {
+	// An array of strings followed by a separator is joined like array::join
+	let args = match <[Value; 2]>::try_from(args) {
+		Ok([Value::Array(arr), chr]) => once(chr).chain(arr).collect(),
+		Ok(args) => args.into(),
+		Err(args) => args,
+	};
 	let mut args = args.into_iter().map(Value::as_string);
 	let chr = args.next().ok_or_else(|| Error::InvalidArguments {
 		name: String::from("string::join"),
@@ -51,20 +245,67 @@ pub fn join(args: Vec<Value>) -> Result<Value, Error> {
 	Ok(strings.join(&chr).into())
 }
 
+pub fn kebabcase((string,): (String,)) -> Result<Value, Error> {
+	Ok(string::case::kebabcase(&string).into())
+}
+
 pub fn len((string,): (String,)) -> Result<Value, Error> {
 	let num = string.chars().count() as i64;
 	Ok(num.into())
 }
 
+pub fn lines((string, keep_trailing): (String, Option<bool>)) -> Result<Value, Error> {
+	let mut lines =
+		string.split('\n').map(|l| l.strip_suffix('\r').unwrap_or(l)).collect::<Vec<_>>();
+	// Trailing empty lines are dropped unless asked for
+	if !keep_trailing.unwrap_or(false) {
+		while lines.last().is_some_and(|l| l.is_empty()) {
+			lines.pop();
+		}
+	}
+	Ok(lines.into())
+}
+
 pub fn lowercase((string,): (String,)) -> Result<Value, Error> {
 	Ok(string.to_lowercase().into())
 }
 
+pub fn pascalcase((string,): (String,)) -> Result<Value, Error> {
+	Ok(string::case::pascalcase(&string).into())
+}
+
 pub fn repeat((val, num): (String, usize)) -> Result<Value, Error> {
 	limit("string::repeat", val.len().saturating_mul(num))?;
 	Ok(val.repeat(num).into())
 }
 
+pub fn mask(
+	(string, keep_start, keep_end, chr): (String, usize, usize, Option<String>),
+) -> Result<Value, Error> {
+	let mut chr = chr.as_deref().unwrap_or("*").chars();
+	let (Some(chr), None) = (chr.next(), chr.next()) else {
+		return Err(Error::InvalidArguments {
+			name: String::from("string::mask"),
+			message: String::from("The mask must be a single character."),
+		});
+	};
+	let len = string.chars().count();
+	// Strings which are too short to keep anything are masked completely
+	let (keep_start, keep_end) = match keep_start.saturating_add(keep_end) < len {
+		true => (keep_start, keep_end),
+		false => (0, 0),
+	};
+	Ok(string
+		.chars()
+		.enumerate()
+		.map(|(i, c)| match i < keep_start || i >= len - keep_end {
+			true => c,
+			false => chr,
+		})
+		.collect::<String>()
+		.into())
+}
+
 pub fn matches((val, regex): (String, Regex)) -> Result<Value, Error> {
 	Ok(regex.0.is_match(&val).into())
 }
@@ -129,6 +370,10 @@ pub fn slug((string,): (String,)) -> Result<Value, Error> {
 	Ok(string::slug::slug(string).into())
 }
 
+pub fn snakecase((string,): (String,)) -> Result<Value, Error> {
+	Ok(string::case::snakecase(&string).into())
+}
+
 pub fn split((val, chr): (String, String)) -> Result<Value, Error> {
 	Ok(val.split(&chr).collect::<Vec<&str>>().into())
 }
@@ -137,6 +382,35 @@ pub fn starts_with((val, chr): (String, String)) -> Result<Value, Error> {
 	Ok(val.starts_with(&chr).into())
 }
 
+pub fn titlecase((string,): (String,)) -> Result<Value, Error> {
+	Ok(string::case::titlecase(&string).into())
+}
+
+/// Transliterates Latin characters and punctuation to their closest ASCII equivalents.
+/// Characters from any other script, such as CJK ideographs, are dropped.
+pub fn to_ascii((string,): (String,)) -> Result<Value, Error> {
+	let mut out = String::with_capacity(string.len());
+	for c in string.chars() {
+		match c {
+			c if c.is_ascii() => out.push(c),
+			// Latin-1 Supplement, Latin Extended-A and Latin Extended-B
+			'\u{0080}'..='\u{024F}'
+			// Latin Extended Additional
+			| '\u{1E00}'..='\u{1EFF}'
+			// General Punctuation
+			| '\u{2000}'..='\u{206F}' => out.push_str(ascii::any_ascii_char(c)),
+			_ => {}
+		}
+	}
+	Ok(out.into())
+}
+
+/// Transliterates characters from any script to their closest ASCII equivalents.
+/// Unlike `string::to_ascii`, characters outside of the Latin script are romanised.
+pub fn transliterate((string,): (String,)) -> Result<Value, Error> {
+	Ok(ascii::any_ascii(&string).into())
+}
+
 pub fn trim((string,): (String,)) -> Result<Value, Error> {
 	Ok(string.trim().into())
 }
@@ -165,6 +439,24 @@ pub mod distance {
 			feature: "string::distance::levenshtein() function".to_string(),
 		})
 	}
+
+	/// Calculates the Levenshtein distance between `needle` and the substring of `haystack`
+	/// which it most closely matches, allowing the match to start and end anywhere.
+	pub(super) fn levenshtein_substring(needle: &[char], haystack: &[char]) -> usize {
+		// Matching may start at any position, so the first row is all zeros
+		let mut prev = vec![0; haystack.len() + 1];
+		let mut curr = vec![0; haystack.len() + 1];
+		for (i, n) in needle.iter().enumerate() {
+			curr[0] = i + 1;
+			for (j, h) in haystack.iter().enumerate() {
+				let cost = usize::from(n != h);
+				curr[j + 1] = (prev[j] + cost).min(prev[j + 1] + 1).min(curr[j] + 1);
+			}
+			std::mem::swap(&mut prev, &mut curr);
+		}
+		// Matching may end at any position, so take the best score in the last row
+		prev.into_iter().min().unwrap_or(needle.len())
+	}
 }
 
 pub mod html {
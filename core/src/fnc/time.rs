@@ -1,9 +1,114 @@
+use crate::ctx::Context;
 use crate::err::Error;
 use crate::sql::datetime::Datetime;
 use crate::sql::duration::Duration;
 use crate::sql::value::Value;
 use chrono::offset::TimeZone;
-use chrono::{DateTime, Datelike, DurationRound, Local, Timelike, Utc};
+use chrono::{
+	DateTime, Datelike, Days, DurationRound, Local, Months, NaiveDate, NaiveTime, SecondsFormat,
+	Timelike, Utc,
+};
+use chrono_tz::Tz;
+use std::cmp::Ordering;
+
+/// Returns the number of whole calendar years between two datetimes.
+fn years_between(name: &str, birth: Datetime, at: Datetime) -> Result<Value, Error> {
+	if at.0 < birth.0 {
+		return Err(Error::InvalidArguments {
+			name: name.to_owned(),
+			message: String::from("The reference date must not be before the birthdate."),
+		});
+	}
+	let (birth, at) = (birth.date_naive(), at.date_naive());
+	let mut years = at.year() - birth.year();
+	// The birthday has not yet occurred in the reference year
+	if (at.month(), at.day()) < (birth.month(), birth.day()) {
+		years -= 1;
+	}
+	Ok(years.into())
+}
+
+/// Returns the start of the fixed-size bucket which contains the datetime.
+fn bucket_start(name: &str, val: Datetime, duration: Duration) -> Result<Datetime, Error> {
+	let invalid = || Error::InvalidArguments {
+		name: name.to_owned(),
+		message: String::from("The duration must be positive and representable in nanoseconds."),
+	};
+	let d = chrono::Duration::from_std(*duration).map_err(|_| invalid())?;
+	if d.is_zero() {
+		return Err(invalid());
+	}
+	val.duration_trunc(d).map(Datetime::from).map_err(|_| invalid())
+}
+
+/// Returns the start of the calendar period which contains the datetime, along
+/// with the start of the following period. Weeks start on a Monday, as in ISO 8601.
+fn period(
+	name: &str,
+	val: Datetime,
+	unit: &str,
+	tz: Option<String>,
+) -> Result<(DateTime<Utc>, DateTime<Utc>), Error> {
+	let tz = match tz {
+		Some(tz) => timezone::parse(name, &tz)?,
+		None => Tz::UTC,
+	};
+	let date = val.0.with_timezone(&tz).date_naive();
+	let start = match unit {
+		"day" => Some(date),
+		"week" => date.checked_sub_days(Days::new(date.weekday().num_days_from_monday().into())),
+		"month" => date.with_day(1),
+		"year" => date.with_ordinal(1),
+		_ => {
+			return Err(Error::InvalidArguments {
+				name: name.to_owned(),
+				message: String::from(
+					"The unit must be one of \"day\", \"week\", \"month\", or \"year\".",
+				),
+			})
+		}
+	};
+	let next = start.and_then(|d| match unit {
+		"day" => d.checked_add_days(Days::new(1)),
+		"week" => d.checked_add_days(Days::new(7)),
+		"month" => d.checked_add_months(Months::new(1)),
+		_ => d.checked_add_months(Months::new(12)),
+	});
+	match (start.and_then(|d| midnight(&tz, d)), next.and_then(|d| midnight(&tz, d))) {
+		(Some(start), Some(next)) => Ok((start, next)),
+		_ => Err(Error::InvalidArguments {
+			name: name.to_owned(),
+			message: String::from("The resulting datetime is out of range."),
+		}),
+	}
+}
+
+/// Returns the first instant of the date in the timezone. Where a daylight
+/// saving transition skips midnight, the day starts when the clocks resume.
+fn midnight(tz: &Tz, date: NaiveDate) -> Option<DateTime<Utc>> {
+	let local = date.and_time(NaiveTime::MIN);
+	tz.from_local_datetime(&local)
+		.earliest()
+		.or_else(|| tz.from_local_datetime(&(local + chrono::Duration::hours(1))).earliest())
+		.map(|v| v.with_timezone(&Utc))
+}
+
+pub fn age(ctx: &Context, (birth,): (Datetime,)) -> Result<Value, Error> {
+	years_between("time::age", birth, ctx.clock().now())
+}
+
+pub fn age_at((birth, at): (Datetime, Datetime)) -> Result<Value, Error> {
+	years_between("time::age_at", birth, at)
+}
+
+pub fn bucket((val, duration): (Datetime, Duration)) -> Result<Value, Error> {
+	bucket_start("time::bucket", val, duration).map(Value::from)
+}
+
+pub fn bucket_key((val, duration): (Datetime, Duration)) -> Result<Value, Error> {
+	let start = bucket_start("time::bucket_key", val, duration)?;
+	Ok(start.to_rfc3339_opts(SecondsFormat::AutoSi, true).into())
+}
 
 pub fn ceil((val, duration): (Datetime, Duration)) -> Result<Value, Error> {
 	match chrono::Duration::from_std(*duration) {
@@ -46,6 +151,24 @@ pub fn day((val,): (Option<Datetime>,)) -> Result<Value, Error> {
 	})
 }
 
+pub fn diff((from, to): (Datetime, Datetime)) -> Result<Value, Error> {
+	// A duration can not be negative, so the direction is returned separately
+	let (duration, sign) = match to.cmp(&from) {
+		Ordering::Less => (from - to, -1),
+		Ordering::Equal => (Duration::default(), 0),
+		Ordering::Greater => (to - from, 1),
+	};
+	Ok(Value::from(map! {
+		"duration".to_string() => Value::from(duration),
+		"sign".to_string() => Value::from(sign),
+	}))
+}
+
+pub fn end_of((val, unit, tz): (Datetime, String, Option<String>)) -> Result<Value, Error> {
+	let (_, next) = period("time::end_of", val, &unit, tz)?;
+	Ok(Datetime::from(next - chrono::Duration::nanoseconds(1)).into())
+}
+
 pub fn floor((val, duration): (Datetime, Duration)) -> Result<Value, Error> {
 	match chrono::Duration::from_std(*duration) {
 		Ok(d) => {
@@ -167,8 +290,12 @@ pub fn micros((val,): (Option<Datetime>,)) -> Result<Value, Error> {
 	})
 }
 
-pub fn now(_: ()) -> Result<Value, Error> {
-	Ok(Datetime::default().into())
+pub fn now(ctx: &Context, _: ()) -> Result<Value, Error> {
+	Ok(ctx.clock().now().into())
+}
+
+pub fn now_monotonic(ctx: &Context, _: ()) -> Result<Value, Error> {
+	Ok(ctx.clock().now_monotonic().into())
 }
 
 pub fn round((val, duration): (Datetime, Duration)) -> Result<Value, Error> {
@@ -200,6 +327,11 @@ pub fn second((val,): (Option<Datetime>,)) -> Result<Value, Error> {
 	})
 }
 
+pub fn start_of((val, unit, tz): (Datetime, String, Option<String>)) -> Result<Value, Error> {
+	let (start, _) = period("time::start_of", val, &unit, tz)?;
+	Ok(Datetime::from(start).into())
+}
+
 pub fn timezone(_: ()) -> Result<Value, Error> {
 	Ok(Local::now().offset().to_string().into())
 }
@@ -301,3 +433,29 @@ pub mod from {
 		}
 	}
 }
+
+pub mod timezone {
+
+	use crate::err::Error;
+	use crate::sql::datetime::Datetime;
+	use crate::sql::value::Value;
+	use chrono::{Offset, SecondsFormat, TimeZone};
+	use chrono_tz::Tz;
+
+	pub(super) fn parse(name: &str, tz: &str) -> Result<Tz, Error> {
+		tz.parse().map_err(|_| Error::InvalidArguments {
+			name: name.to_owned(),
+			message: format!("The timezone '{tz}' is not a valid IANA timezone name."),
+		})
+	}
+
+	pub fn convert((val, tz): (Datetime, String)) -> Result<Value, Error> {
+		let tz = parse("time::timezone::convert", &tz)?;
+		Ok(val.0.with_timezone(&tz).to_rfc3339_opts(SecondsFormat::AutoSi, true).into())
+	}
+
+	pub fn offset((val, tz): (Datetime, String)) -> Result<Value, Error> {
+		let tz = parse("time::timezone::offset", &tz)?;
+		Ok(tz.offset_from_utc_datetime(&val.0.naive_utc()).fix().to_string().into())
+	}
+}
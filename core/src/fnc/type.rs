@@ -7,10 +7,19 @@ use crate::err::Error;
 use crate::sql::table::Table;
 use crate::sql::thing::Thing;
 use crate::sql::value::Value;
-use crate::sql::{Id, Kind, Range, Strand};
+use crate::sql::{Array, Geometry, Id, Kind, Number, Range, Strand};
 use crate::syn;
 use reblessive::tree::Stk;
 
+pub fn assert((val, kind, message): (Value, String, String)) -> Result<Value, Error> {
+	let kind = syn::kind(&kind)?;
+	// The value is only checked, and is returned unchanged
+	match val.clone().coerce_to(&kind) {
+		Ok(_) => Ok(val),
+		Err(_) => Err(Error::Thrown(message)),
+	}
+}
+
 pub fn bool((val,): (Value,)) -> Result<Value, Error> {
 	val.convert_to_bool().map(Value::from)
 }
@@ -19,6 +28,29 @@ pub fn bytes((val,): (Value,)) -> Result<Value, Error> {
 	val.convert_to_bytes().map(Value::from)
 }
 
+pub fn cast_array((array, kind): (Array, String)) -> Result<Value, Error> {
+	let kind = syn::kind(&kind)?;
+	array
+		.into_iter()
+		.enumerate()
+		.map(|(i, v)| {
+			v.coerce_to(&kind).map_err(|e| Error::InvalidArguments {
+				name: String::from("type::cast_array"),
+				message: format!("The element at index {i} could not be coerced: {e}"),
+			})
+		})
+		.collect::<Result<Array, _>>()
+		.map(Value::from)
+}
+
+pub fn coerce((val, kind): (Value, String)) -> Result<Value, Error> {
+	val.coerce_to(&syn::kind(&kind)?)
+}
+
+pub fn convert((val, kind): (Value, String)) -> Result<Value, Error> {
+	val.convert_to(&syn::kind(&kind)?)
+}
+
 pub fn datetime((val,): (Value,)) -> Result<Value, Error> {
 	val.convert_to_datetime().map(Value::from)
 }
@@ -27,10 +59,30 @@ pub fn decimal((val,): (Value,)) -> Result<Value, Error> {
 	val.convert_to_decimal().map(Value::from)
 }
 
+pub fn depth((val,): (Value,)) -> Result<Value, Error> {
+	Ok(val.depth().into())
+}
+
 pub fn duration((val,): (Value,)) -> Result<Value, Error> {
 	val.convert_to_duration().map(Value::from)
 }
 
+pub fn r#enum((val, allowed): (Value, Array)) -> Result<Value, Error> {
+	if allowed.is_empty() {
+		return Err(Error::InvalidArguments {
+			name: String::from("type::enum"),
+			message: String::from("Expected at least one allowed value."),
+		});
+	}
+	match allowed.contains(&val) {
+		true => Ok(val),
+		false => Err(Error::CoerceTo {
+			from: val,
+			into: allowed.iter().map(|v| v.to_string()).collect::<Vec<_>>().join(" | "),
+		}),
+	}
+}
+
 pub async fn field(
 	(stk, ctx, opt, doc): (&mut Stk, &Context, Option<&Options>, Option<&CursorDoc>),
 	(val,): (String,),
@@ -83,6 +135,61 @@ pub fn int((val,): (Value,)) -> Result<Value, Error> {
 	val.convert_to_int().map(Value::from)
 }
 
+pub fn kind_of((val,): (Value,)) -> Result<Value, Error> {
+	Ok(kind(&val).to_string().into())
+}
+
+/// Returns the most specific kind which describes the value. Arrays whose
+/// items share a kind are described by that kind, and otherwise as `array`.
+fn kind(val: &Value) -> Kind {
+	match val {
+		Value::None => Kind::Option(Box::new(Kind::Any)),
+		Value::Null => Kind::Null,
+		Value::Bool(_) => Kind::Bool,
+		Value::Bytes(_) => Kind::Bytes,
+		Value::Datetime(_) => Kind::Datetime,
+		Value::Duration(_) => Kind::Duration,
+		Value::Number(Number::Int(_)) => Kind::Int,
+		Value::Number(Number::Float(_)) => Kind::Float,
+		Value::Number(Number::Decimal(_)) => Kind::Decimal,
+		Value::Object(_) => Kind::Object,
+		Value::Strand(_) => Kind::String,
+		Value::Uuid(_) => Kind::Uuid,
+		Value::Closure(_) => Kind::Function(None, None),
+		Value::Thing(v) => Kind::Record(vec![Table::from(v.tb.as_str())]),
+		Value::Geometry(v) => Kind::Geometry(vec![match v {
+			Geometry::Point(_) => "point".to_owned(),
+			Geometry::Line(_) => "line".to_owned(),
+			Geometry::Polygon(_) => "polygon".to_owned(),
+			Geometry::MultiPoint(_) => "multipoint".to_owned(),
+			Geometry::MultiLine(_) => "multiline".to_owned(),
+			Geometry::MultiPolygon(_) => "multipolygon".to_owned(),
+			Geometry::Collection(_) => "collection".to_owned(),
+		}]),
+		Value::Array(v) => {
+			let mut kinds = v.iter().map(kind);
+			let item = match kinds.next() {
+				Some(first) => kinds.try_fold(first, |a, b| match (a, b) {
+					(a, b) if a == b => Some(a),
+					// Records of different tables are joined into one kind
+					(Kind::Record(mut a), Kind::Record(b)) => {
+						for tb in b {
+							if !a.contains(&tb) {
+								a.push(tb);
+							}
+						}
+						Some(Kind::Record(a))
+					}
+					_ => None,
+				}),
+				None => None,
+			};
+			Kind::Array(Box::new(item.unwrap_or(Kind::Any)), None)
+		}
+		_ => Kind::Any,
+	}
+}
+
 pub fn number((val,): (Value,)) -> Result<Value, Error> {
 	val.convert_to_number().map(Value::from)
 }
@@ -0,0 +1,74 @@
+/// Splits a string into its words, breaking on whitespace,
+/// underscores, hyphens, and on any change from a lowercase
+/// to an uppercase character (including the end of an acronym).
+pub fn words(s: &str) -> Vec<String> {
+	let mut words = Vec::new();
+	for part in s.split(|c: char| c.is_whitespace() || c == '_' || c == '-') {
+		let chars = part.chars().collect::<Vec<_>>();
+		let mut word = String::new();
+		for (i, &c) in chars.iter().enumerate() {
+			if c.is_uppercase() && !word.is_empty() {
+				let prev = chars[i - 1];
+				let next = chars.get(i + 1);
+				// Break on a camel hump, or at the last capital of an acronym
+				if !prev.is_uppercase() || next.is_some_and(|n| n.is_lowercase()) {
+					words.push(std::mem::take(&mut word));
+				}
+			}
+			word.push(c);
+		}
+		if !word.is_empty() {
+			words.push(word);
+		}
+	}
+	words
+}
+
+/// Uppercases the first character of a word and lowercases the rest.
+fn capitalise(word: &str) -> String {
+	let mut chars = word.chars();
+	match chars.next() {
+		Some(c) => c.to_uppercase().chain(chars.flat_map(char::to_lowercase)).collect(),
+		None => String::new(),
+	}
+}
+
+pub fn titlecase(s: &str) -> String {
+	words(s).iter().map(|w| capitalise(w)).collect::<Vec<_>>().join(" ")
+}
+
+pub fn camelcase(s: &str) -> String {
+	words(s)
+		.iter()
+		.enumerate()
+		.map(|(i, w)| match i {
+			0 => w.to_lowercase(),
+			_ => capitalise(w),
+		})
+		.collect()
+}
+
+pub fn pascalcase(s: &str) -> String {
+	words(s).iter().map(|w| capitalise(w)).collect()
+}
+
+pub fn snakecase(s: &str) -> String {
+	words(s).iter().map(|w| w.to_lowercase()).collect::<Vec<_>>().join("_")
+}
+
+pub fn kebabcase(s: &str) -> String {
+	words(s).iter().map(|w| w.to_lowercase()).collect::<Vec<_>>().join("-")
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn splits_words() {
+		assert_eq!(words("hello world_foo"), vec!["hello", "world", "foo"]);
+		assert_eq!(words("helloWorld-foo"), vec!["hello", "World", "foo"]);
+		assert_eq!(words("HTTPServer error"), vec!["HTTP", "Server", "error"]);
+		assert_eq!(words("  __--  "), Vec::<String>::new());
+	}
+}
@@ -0,0 +1,112 @@
+/// The kind of change which a diff segment represents
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Op {
+	Equal,
+	Insert,
+	Delete,
+}
+
+impl Op {
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			Op::Equal => "equal",
+			Op::Insert => "insert",
+			Op::Delete => "delete",
+		}
+	}
+}
+
+/// Splits a string into lines, keeping the line endings
+pub fn lines(s: &str) -> Vec<&str> {
+	s.split_inclusive('\n').collect()
+}
+
+/// Splits a string into alternating runs of whitespace and non-whitespace
+pub fn words(s: &str) -> Vec<&str> {
+	let mut out = Vec::new();
+	let mut start = 0;
+	let mut space = None;
+	for (i, c) in s.char_indices() {
+		let ws = c.is_whitespace();
+		if space.is_some_and(|s| s != ws) {
+			out.push(&s[start..i]);
+			start = i;
+		}
+		space = Some(ws);
+	}
+	if start < s.len() {
+		out.push(&s[start..]);
+	}
+	out
+}
+
+/// Splits a string into its individual characters
+pub fn chars(s: &str) -> Vec<&str> {
+	s.char_indices().map(|(i, c)| &s[i..i + c.len_utf8()]).collect()
+}
+
+/// The number of cells needed to diff the tokens, once any shared prefix and suffix is removed
+pub fn cells(a: &[&str], b: &[&str]) -> usize {
+	let (pre, suf) = affixes(a, b);
+	let (n, m) = (a.len() - pre - suf, b.len() - pre - suf);
+	(n + 1).saturating_mul(m + 1)
+}
+
+/// Computes the diff between two token lists using their longest common subsequence
+pub fn diff(a: &[&str], b: &[&str]) -> Vec<(Op, String)> {
+	let mut out: Vec<(Op, String)> = Vec::new();
+	let mut push = |op: Op, text: &str| match out.last_mut() {
+		Some((last, s)) if *last == op => s.push_str(text),
+		_ => out.push((op, text.to_owned())),
+	};
+	// Shared tokens at the start and end do not need comparing
+	let (pre, suf) = affixes(a, b);
+	for t in &a[..pre] {
+		push(Op::Equal, t);
+	}
+	let (x, y) = (&a[pre..a.len() - suf], &b[pre..b.len() - suf]);
+	let (n, m) = (x.len(), y.len());
+	// Build the table of common subsequence lengths from the end
+	let mut table = vec![0u32; (n + 1) * (m + 1)];
+	let at = |i: usize, j: usize| i * (m + 1) + j;
+	for i in (0..n).rev() {
+		for j in (0..m).rev() {
+			table[at(i, j)] = match x[i] == y[j] {
+				true => table[at(i + 1, j + 1)] + 1,
+				false => table[at(i + 1, j)].max(table[at(i, j + 1)]),
+			};
+		}
+	}
+	// Walk the table to produce the edits
+	let (mut i, mut j) = (0, 0);
+	while i < n && j < m {
+		if x[i] == y[j] {
+			push(Op::Equal, x[i]);
+			i += 1;
+			j += 1;
+		} else if table[at(i + 1, j)] >= table[at(i, j + 1)] {
+			push(Op::Delete, x[i]);
+			i += 1;
+		} else {
+			push(Op::Insert, y[j]);
+			j += 1;
+		}
+	}
+	for t in &x[i..] {
+		push(Op::Delete, t);
+	}
+	for t in &y[j..] {
+		push(Op::Insert, t);
+	}
+	for t in &a[a.len() - suf..] {
+		push(Op::Equal, t);
+	}
+	out
+}
+
+/// Counts the tokens shared at the start and at the end of both lists
+fn affixes(a: &[&str], b: &[&str]) -> (usize, usize) {
+	let pre = a.iter().zip(b).take_while(|(x, y)| x == y).count();
+	let suf = a[pre..].iter().rev().zip(b[pre..].iter().rev()).take_while(|(x, y)| x == y).count();
+	(pre, suf)
+}
@@ -0,0 +1,47 @@
+use std::ops::Range;
+
+/// Finds the byte ranges of the text which match any of the terms, ignoring case.
+/// Overlapping matches are merged, so that the returned ranges never intersect.
+pub fn ranges<S: AsRef<str>>(text: &str, terms: &[S]) -> Vec<Range<usize>> {
+	let terms: Vec<Vec<char>> = terms
+		.iter()
+		.map(|t| t.as_ref().chars().flat_map(char::to_lowercase).collect::<Vec<_>>())
+		.filter(|t| !t.is_empty())
+		.collect();
+	// Find every match of every term, in order of position
+	let mut found: Vec<Range<usize>> = Vec::new();
+	for (start, _) in text.char_indices() {
+		for term in &terms {
+			if let Some(len) = prefix(&text[start..], term) {
+				found.push(start..start + len);
+			}
+		}
+	}
+	found.sort_by_key(|r| (r.start, r.end));
+	// Merge any matches which overlap
+	let mut out: Vec<Range<usize>> = Vec::new();
+	for r in found {
+		match out.last_mut() {
+			Some(last) if r.start < last.end => last.end = last.end.max(r.end),
+			_ => out.push(r),
+		}
+	}
+	out
+}
+
+/// Returns the length in bytes of the start of the text which matches the lowercased term
+fn prefix(text: &str, term: &[char]) -> Option<usize> {
+	let mut pos = 0;
+	for (i, c) in text.char_indices() {
+		if pos == term.len() {
+			return Some(i);
+		}
+		for c in c.to_lowercase() {
+			if term.get(pos) != Some(&c) {
+				return None;
+			}
+			pos += 1;
+		}
+	}
+	(pos == term.len()).then_some(text.len())
+}
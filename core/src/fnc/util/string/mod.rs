@@ -1,2 +1,5 @@
+pub mod case;
+pub mod diff;
 pub mod fuzzy;
+pub mod highlight;
 pub mod slug;
@@ -38,6 +38,16 @@ pub trait Transaction {
 	/// will return an [`Error::TxReadonly`] error.
 	fn writeable(&self) -> bool;
 
+	/// Check if reads skip conflict checking.
+	///
+	/// If the transaction reads from a snapshot of the datastore,
+	/// then concurrent writes to the keys which it reads will not
+	/// cause it to conflict. This is only used by storage engines
+	/// which support eventually consistent reads.
+	fn snapshot(&self) -> bool {
+		false
+	}
+
 	/// Cancel a transaction.
 	///
 	/// This reverses all changes made within the transaction.
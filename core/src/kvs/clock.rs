@@ -1,8 +1,9 @@
 use crate::dbs::node::Timestamp;
 use crate::sql;
-use sql::Duration;
-use std::sync::atomic::AtomicU64;
+use chrono::{DateTime, Utc};
+use sql::{Datetime, Duration};
 use std::sync::atomic::Ordering;
+use std::sync::atomic::{AtomicI64, AtomicU64};
 #[cfg(not(target_arch = "wasm32"))]
 use std::time::{SystemTime, UNIX_EPOCH};
 #[cfg(target_arch = "wasm32")]
@@ -135,13 +136,72 @@ impl Default for SystemClock {
 	}
 }
 
+/// The last time which was returned from a monotonic clock read
+static MONOTONIC: AtomicI64 = AtomicI64::new(i64::MIN);
+
+/// QueryClock is the source of the current time when computing queries,
+/// for instance in `time::now()`. It can be frozen to make the results
+/// of time dependent functions deterministic in tests.
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub enum QueryClock {
+	/// Read the current time from the system
+	#[default]
+	System,
+	/// Always return the same point in time
+	Fixed(Datetime),
+}
+
+impl QueryClock {
+	/// Get the current time from this clock
+	pub fn now(&self) -> Datetime {
+		match self {
+			QueryClock::System => Datetime::default(),
+			QueryClock::Fixed(v) => v.clone(),
+		}
+	}
+
+	/// Get the current time from this clock, ensuring that the system
+	/// time never goes backwards between subsequent calls
+	pub fn now_monotonic(&self) -> Datetime {
+		match self {
+			QueryClock::System => {
+				let now = Utc::now();
+				let Some(nanos) = now.timestamp_nanos_opt() else {
+					return now.into();
+				};
+				// Never return a time earlier than a previous read
+				let last = MONOTONIC.fetch_max(nanos, Ordering::SeqCst);
+				match last > nanos {
+					true => DateTime::from_timestamp_nanos(last).into(),
+					false => now.into(),
+				}
+			}
+			QueryClock::Fixed(v) => v.clone(),
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
-	use crate::kvs::clock::SystemClock;
+	use crate::kvs::clock::{QueryClock, SystemClock};
+	use crate::sql::Datetime;
 
 	#[test]
 	fn get_clock_now() {
 		let clock = SystemClock::new();
 		let _ = clock.now();
 	}
+
+	#[test]
+	fn query_clock_monotonic() {
+		let clock = QueryClock::System;
+		let a = clock.now_monotonic();
+		let b = clock.now_monotonic();
+		assert!(b >= a);
+		let fixed = Datetime::try_from("2024-01-01T00:00:00Z").unwrap();
+		let clock = QueryClock::Fixed(fixed.clone());
+		assert_eq!(clock.now(), fixed);
+		assert_eq!(clock.now_monotonic(), fixed);
+	}
 }
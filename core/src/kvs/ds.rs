@@ -12,10 +12,12 @@ use crate::err::Error;
 use crate::iam::jwks::JwksCache;
 use crate::iam::{Action, Auth, Error as IamError, Resource, Role};
 use crate::idx::trees::store::IndexStores;
-use crate::kvs::clock::SizedClock;
 #[allow(unused_imports)]
 use crate::kvs::clock::SystemClock;
-use crate::kvs::{LockType, LockType::*, TransactionType, TransactionType::*};
+use crate::kvs::clock::{QueryClock, SizedClock};
+use crate::kvs::{
+	Consistency, ExportConfig, LockType, LockType::*, TransactionType, TransactionType::*,
+};
 use crate::sql::{statements::DefineUserStatement, Base, Query, Value};
 use crate::syn;
 use crate::vs::{conv, Versionstamp};
@@ -73,6 +75,8 @@ pub struct Datastore {
 	pub(super) notification_channel: Option<(Sender<Notification>, Receiver<Notification>)>,
 	// Clock for tracking time. It is read only and accessible to all transactions. It is behind a mutex as tests may write to it.
 	pub(super) clock: Arc<SizedClock>,
+	// The clock used for the current time when computing queries
+	query_clock: QueryClock,
 	// The index store cache
 	index_stores: IndexStores,
 	#[cfg(feature = "jwks")]
@@ -290,6 +294,7 @@ impl Datastore {
 			id: Uuid::new_v4(),
 			inner,
 			clock,
+			query_clock: QueryClock::default(),
 			strict: false,
 			auth_enabled: false,
 			query_timeout: None,
@@ -346,6 +351,12 @@ impl Datastore {
 		self
 	}
 
+	/// Set the clock used for the current time when computing queries
+	pub fn with_clock(mut self, clock: QueryClock) -> Self {
+		self.query_clock = clock;
+		self
+	}
+
 	/// Set specific capabilities for this Datastore
 	pub fn with_capabilities(mut self, caps: Capabilities) -> Self {
 		self.capabilities = caps;
@@ -374,6 +385,11 @@ impl Datastore {
 		self.auth_enabled
 	}
 
+	/// The capabilities which are allowed for this Datastore
+	pub fn capabilities(&self) -> &Capabilities {
+		&self.capabilities
+	}
+
 	pub fn id(&self) -> Uuid {
 		self.id
 	}
@@ -545,12 +561,49 @@ impl Datastore {
 	///     Ok(())
 	/// }
 	/// ```
-	#[allow(unreachable_code)]
 	pub async fn transaction(
 		&self,
 		write: TransactionType,
 		lock: LockType,
 	) -> Result<Transaction, Error> {
+		self.transaction_with_consistency(write, lock, Consistency::Strong).await
+	}
+
+	/// Checks that the underlying storage engine supports a read consistency level
+	///
+	/// Returns an [`Error::Unimplemented`] if the consistency level is not supported.
+	pub fn check_consistency(&self, consistency: Consistency) -> Result<(), Error> {
+		let supported = match consistency {
+			Consistency::Strong => true,
+			// Only FoundationDB can skip conflict checking with snapshot reads
+			Consistency::Eventual => match &self.inner {
+				#[cfg(feature = "kv-fdb")]
+				Inner::FoundationDB(_) => true,
+				#[allow(unreachable_patterns)]
+				_ => false,
+			},
+		};
+		match supported {
+			true => Ok(()),
+			false => Err(Error::Unimplemented(format!(
+				"The '{consistency}' consistency level is not supported by this storage engine"
+			))),
+		}
+	}
+
+	/// Create a new transaction on this datastore with a specific read consistency level
+	///
+	/// Returns an [`Error::Unimplemented`] if the storage engine does not
+	/// support the requested consistency level.
+	#[allow(unreachable_code)]
+	pub async fn transaction_with_consistency(
+		&self,
+		write: TransactionType,
+		lock: LockType,
+		consistency: Consistency,
+	) -> Result<Transaction, Error> {
+		// Check that the consistency level is supported
+		self.check_consistency(consistency)?;
 		// Specify if the transaction is writeable
 		#[allow(unused_variables)]
 		let write = match write {
@@ -588,7 +641,7 @@ impl Datastore {
 			}
 			#[cfg(feature = "kv-fdb")]
 			Inner::FoundationDB(v) => {
-				let tx = v.transaction(write, lock).await?;
+				let tx = v.transaction(write, lock, consistency).await?;
 				super::tr::Inner::FoundationDB(tx)
 			}
 			#[cfg(feature = "kv-surrealkv")]
@@ -684,7 +737,7 @@ impl Datastore {
 			.with_strict(self.strict)
 			.with_auth_enabled(self.auth_enabled);
 		// Create a new query executor
-		let mut exe = Executor::new(self);
+		let mut exe = Executor::new(self, sess.cl);
 		// Create a default context
 		let mut ctx = MutableContext::from_ds(
 			self.query_timeout,
@@ -699,6 +752,8 @@ impl Datastore {
 			))]
 			self.temporary_directory.clone(),
 		)?;
+		// Set the clock for the current time
+		ctx.add_clock(self.query_clock.clone());
 		// Setup the notification channel
 		if let Some(channel) = &self.notification_channel {
 			ctx.add_notifications(Some(&channel.0));
@@ -765,6 +820,8 @@ impl Datastore {
 		let mut ctx = MutableContext::default();
 		// Set context capabilities
 		ctx.add_capabilities(self.capabilities.clone());
+		// Set the clock for the current time
+		ctx.add_clock(self.query_clock.clone());
 		// Set the global query timeout
 		if let Some(timeout) = self.query_timeout {
 			ctx.add_timeout(timeout)?;
@@ -778,7 +835,10 @@ impl Datastore {
 		// Store the query variables
 		vars.attach(&mut ctx)?;
 		// Start a new transaction
-		let txn = self.transaction(val.writeable().into(), Optimistic).await?.enclose();
+		let txn = self
+			.transaction_with_consistency(val.writeable().into(), Optimistic, sess.cl)
+			.await?
+			.enclose();
 		// Store the transaction
 		ctx.set_transaction(txn.clone());
 		// Freeze the context
@@ -844,6 +904,8 @@ impl Datastore {
 		let mut ctx = MutableContext::default();
 		// Set context capabilities
 		ctx.add_capabilities(self.capabilities.clone());
+		// Set the clock for the current time
+		ctx.add_clock(self.query_clock.clone());
 		// Set the global query timeout
 		if let Some(timeout) = self.query_timeout {
 			ctx.add_timeout(timeout)?;
@@ -857,7 +919,10 @@ impl Datastore {
 		// Store the query variables
 		vars.attach(&mut ctx)?;
 		// Start a new transaction
-		let txn = self.transaction(val.writeable().into(), Optimistic).await?.enclose();
+		let txn = self
+			.transaction_with_consistency(val.writeable().into(), Optimistic, sess.cl)
+			.await?
+			.enclose();
 		// Store the transaction
 		ctx.set_transaction(txn.clone());
 		// Free the context
@@ -916,6 +981,17 @@ impl Datastore {
 		&self,
 		sess: &Session,
 		chn: Sender<Vec<u8>>,
+	) -> Result<impl Future<Output = Result<(), Error>>, Error> {
+		self.export_with_config(sess, ExportConfig::default(), chn).await
+	}
+
+	/// Performs a database export as SQL, including only the configured parts
+	#[instrument(level = "debug", target = "surrealdb::core::kvs::ds", skip_all)]
+	pub async fn export_with_config(
+		&self,
+		sess: &Session,
+		cfg: ExportConfig,
+		chn: Sender<Vec<u8>>,
 	) -> Result<impl Future<Output = Result<(), Error>>, Error> {
 		// Check if the session has expired
 		if sess.expired() {
@@ -928,7 +1004,7 @@ impl Datastore {
 		// Return an async export job
 		Ok(async move {
 			// Process the export
-			txn.export(&ns, &db, chn).await?;
+			txn.export(&ns, &db, cfg, chn).await?;
 			// Everything ok
 			Ok(())
 		})
@@ -993,6 +1069,8 @@ mod test {
 		let mut ctx = MutableContext::default();
 		// Set context capabilities
 		ctx.add_capabilities(dbs.capabilities.clone());
+		// Set the clock for the current time
+		ctx.add_clock(dbs.query_clock.clone());
 		// Start a new transaction
 		let txn = dbs.transaction(val.writeable().into(), Optimistic).await?;
 		// Store the transaction
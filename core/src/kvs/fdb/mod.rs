@@ -5,6 +5,7 @@ mod cnf;
 use crate::err::Error;
 use crate::key::debug::Sprintable;
 use crate::kvs::Check;
+use crate::kvs::Consistency;
 use crate::kvs::Key;
 use crate::kvs::Val;
 use crate::vs::Versionstamp;
@@ -42,6 +43,8 @@ pub struct Transaction {
 	done: bool,
 	/// Should this transaction lock?
 	lock: bool,
+	/// The read consistency level of the transaction
+	consistency: Consistency,
 	/// Is the transaction writeable?
 	write: bool,
 	/// Should we check unhandled transactions?
@@ -124,7 +127,12 @@ impl Datastore {
 		}
 	}
 	/// Start a new transaction
-	pub(crate) async fn transaction(&self, write: bool, lock: bool) -> Result<Transaction, Error> {
+	pub(crate) async fn transaction(
+		&self,
+		write: bool,
+		lock: bool,
+		consistency: Consistency,
+	) -> Result<Transaction, Error> {
 		// Specify the check level
 		#[cfg(not(debug_assertions))]
 		let check = Check::Warn;
@@ -135,6 +143,7 @@ impl Datastore {
 			Ok(inner) => Ok(Transaction {
 				done: false,
 				lock,
+				consistency,
 				check,
 				write,
 				inner: Some(inner),
@@ -153,10 +162,14 @@ impl Transaction {
 	/// that `lock=true` is effectively specifying that we should ensure
 	/// transactions are serializable. If the transaction is writeable, we also
 	/// assume that the user never wants to lose serializability, so we go with
-	/// the standard FoundationDB serializable more in that scenario.
+	/// the standard FoundationDB serializable more in that scenario. Snapshot
+	/// reads are only used when eventual consistency has been requested.
 	#[inline(always)]
 	fn snapshot(&self) -> bool {
-		!self.write && !self.lock
+		match self.consistency {
+			Consistency::Strong => false,
+			Consistency::Eventual => !self.write && !self.lock,
+		}
 	}
 }
 
@@ -176,6 +189,11 @@ impl super::api::Transaction for Transaction {
 		self.write
 	}
 
+	/// Check if reads skip conflict checking
+	fn snapshot(&self) -> bool {
+		Transaction::snapshot(self)
+	}
+
 	/// Cancel a transaction
 	#[instrument(level = "trace", target = "surrealdb::core::kvs::api", skip(self))]
 	async fn cancel(&mut self) -> Result<(), Error> {
@@ -36,7 +36,9 @@ mod tikv;
 #[cfg(test)]
 mod tests;
 
+pub use self::clock::QueryClock;
 pub use self::ds::*;
+pub use self::export::ExportConfig;
 pub use self::kv::*;
 pub use self::live::*;
 pub use self::tr::*;
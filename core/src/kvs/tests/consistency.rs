@@ -0,0 +1,54 @@
+#[tokio::test]
+#[serial]
+async fn consistency() {
+	use crate::err::Error;
+	use crate::kvs::Consistency;
+	// Create a new datastore
+	let node_id = Uuid::parse_str("6b5b4c6f-9bd4-4b0a-8d3b-6f2a3c1b7e41").unwrap();
+	let clock = Arc::new(SizedClock::Fake(FakeClock::new(Timestamp::default())));
+	let (ds, kvs) = new_ds(node_id, clock).await;
+	// Insert an initial key with strong consistency
+	assert!(ds.check_consistency(Consistency::Strong).is_ok());
+	let mut tx = ds
+		.transaction_with_consistency(Write, Optimistic, Consistency::Strong)
+		.await
+		.unwrap()
+		.inner();
+	assert!(!tx.snapshot());
+	tx.set("test", "some text").await.unwrap();
+	tx.commit().await.unwrap();
+	// Strongly consistent reads never skip conflict checking
+	let mut tx = ds
+		.transaction_with_consistency(Read, Optimistic, Consistency::Strong)
+		.await
+		.unwrap()
+		.inner();
+	assert!(!tx.snapshot());
+	tx.cancel().await.unwrap();
+	// Eventual consistency is only supported by some engines
+	let res = ds.transaction_with_consistency(Read, Optimistic, Consistency::Eventual).await;
+	match kvs {
+		Kvs::Fdb => {
+			assert!(ds.check_consistency(Consistency::Eventual).is_ok());
+			// Eventually consistent reads are snapshot reads
+			let mut tx = res.unwrap().inner();
+			assert!(tx.snapshot());
+			let val = tx.get("test", None).await.unwrap().unwrap();
+			assert_eq!(val, b"some text");
+			tx.cancel().await.unwrap();
+			// Writeable transactions remain serializable
+			let mut tx = ds
+				.transaction_with_consistency(Write, Optimistic, Consistency::Eventual)
+				.await
+				.unwrap()
+				.inner();
+			assert!(!tx.snapshot());
+			tx.cancel().await.unwrap();
+		}
+		_ => {
+			let err = ds.check_consistency(Consistency::Eventual);
+			assert!(matches!(err, Err(Error::Unimplemented(_))));
+			assert!(matches!(res, Err(Error::Unimplemented(_))));
+		}
+	}
+}
@@ -60,6 +60,26 @@ impl From<bool> for LockType {
 	}
 }
 
+/// Specifies the read consistency level of the transaction.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+#[non_exhaustive]
+pub enum Consistency {
+	/// Reads are serializable with respect to concurrent writes
+	#[default]
+	Strong,
+	/// Reads may skip conflict checking against concurrent writes
+	Eventual,
+}
+
+impl fmt::Display for Consistency {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Consistency::Strong => f.write_str("strong"),
+			Consistency::Eventual => f.write_str("eventual"),
+		}
+	}
+}
+
 /// A set of undoable updates and requests against a dataset.
 #[allow(dead_code)]
 #[non_exhaustive]
@@ -158,6 +178,16 @@ impl Transactor {
 		expand_inner!(&self.inner, v => { v.closed() })
 	}
 
+	/// Check if reads skip conflict checking.
+	///
+	/// If the transaction reads from a snapshot of the datastore,
+	/// then concurrent writes to the keys which it reads will not
+	/// cause it to conflict.
+	#[instrument(level = "trace", target = "surrealdb::core::kvs::tr", skip_all)]
+	pub(crate) fn snapshot(&self) -> bool {
+		expand_inner!(&self.inner, v => { v.snapshot() })
+	}
+
 	/// Cancel a transaction.
 	///
 	/// This reverses all changes made within the transaction.
@@ -8,7 +8,9 @@ pub trait Take {
 	fn needs_two(self) -> Result<(Value, Value), RpcError>;
 	fn needs_three(self) -> Result<(Value, Value, Value), RpcError>;
 	fn needs_one_or_two(self) -> Result<(Value, Value), RpcError>;
+	fn needs_two_or_three(self) -> Result<(Value, Value, Value), RpcError>;
 	fn needs_one_two_or_three(self) -> Result<(Value, Value, Value), RpcError>;
+	fn needs_one_two_three_or_four(self) -> Result<(Value, Value, Value, Value), RpcError>;
 	fn needs_three_or_four(self) -> Result<(Value, Value, Value, Value), RpcError>;
 }
 
@@ -60,6 +62,18 @@ impl Take for Array {
 		}
 	}
 	/// Convert the array to three arguments
+	fn needs_two_or_three(self) -> Result<(Value, Value, Value), RpcError> {
+		if self.len() < 2 || self.len() > 3 {
+			return Err(RpcError::InvalidParams);
+		}
+		let mut x = self.into_iter();
+		match (x.next(), x.next(), x.next()) {
+			(Some(a), Some(b), Some(c)) => Ok((a, b, c)),
+			(Some(a), Some(b), None) => Ok((a, b, Value::None)),
+			(_, _, _) => Ok((Value::None, Value::None, Value::None)),
+		}
+	}
+	/// Convert the array to three arguments
 	fn needs_one_two_or_three(self) -> Result<(Value, Value, Value), RpcError> {
 		if self.is_empty() || self.len() > 3 {
 			return Err(RpcError::InvalidParams);
@@ -73,6 +87,19 @@ impl Take for Array {
 		}
 	}
 	/// Convert the array to four arguments
+	fn needs_one_two_three_or_four(self) -> Result<(Value, Value, Value, Value), RpcError> {
+		if self.is_empty() || self.len() > 4 {
+			return Err(RpcError::InvalidParams);
+		}
+		let mut x = self.into_iter();
+		Ok((
+			x.next().unwrap_or_default(),
+			x.next().unwrap_or_default(),
+			x.next().unwrap_or_default(),
+			x.next().unwrap_or_default(),
+		))
+	}
+	/// Convert the array to four arguments
 	fn needs_three_or_four(self) -> Result<(Value, Value, Value, Value), RpcError> {
 		if self.len() < 3 || self.len() > 4 {
 			return Err(RpcError::InvalidParams);
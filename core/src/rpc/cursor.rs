@@ -0,0 +1,98 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+
+use crate::sql::{Array, Expression, Field, Fields, Idiom, Idioms, Operator, Order, Orders, Value};
+
+use super::{request::ID, rpc_error::RpcError};
+
+/// Sorts rows by their record id after any other sort fields, so that
+/// every row has a unique sort key and no rows share a page boundary
+pub fn with_id(mut orders: Orders) -> Orders {
+	let id = Idiom::from(ID.as_ref());
+	if !orders.iter().any(|o| o.order == id) {
+		orders.0.push(Order {
+			order: id,
+			random: false,
+			collate: false,
+			numeric: false,
+			direction: true,
+		});
+	}
+	orders
+}
+
+/// Checks that a sort field is output unchanged, so that its value in
+/// a row can be compared against the field which is stored
+pub fn is_projected(fields: &Fields, omit: Option<&Idioms>, idiom: &Idiom) -> bool {
+	if omit.is_some_and(|v| v.iter().any(|v| idiom.starts_with(v))) {
+		return false;
+	}
+	let mut projected = fields.is_all();
+	for field in fields.other() {
+		if let Field::Single {
+			expr,
+			alias,
+		} = field
+		{
+			match alias {
+				// An alias replaces the stored value of the field
+				Some(alias) if idiom.starts_with(alias) || alias.starts_with(idiom) => {
+					return false
+				}
+				Some(_) => {}
+				None => match expr {
+					Value::Idiom(v) if idiom.starts_with(v) => projected = true,
+					_ => {}
+				},
+			}
+		}
+	}
+	projected
+}
+
+/// Encodes the sort key of the last row of a page into an opaque cursor
+pub fn encode(orders: &Orders, row: &Value) -> Result<String, RpcError> {
+	let key: Array = orders.iter().map(|o| row.pick(&o.order)).collect::<Vec<_>>().into();
+	let bytes = Value::Array(key).to_cbor()?;
+	Ok(URL_SAFE_NO_PAD.encode(bytes))
+}
+
+/// Decodes a cursor into the sort key it was created from, checking
+/// that it matches the fields the query is ordered by
+pub fn decode(orders: &Orders, cursor: &str) -> Result<Vec<Value>, RpcError> {
+	let bytes = URL_SAFE_NO_PAD.decode(cursor).map_err(|_| RpcError::InvalidParams)?;
+	match Value::from_cbor(&bytes) {
+		Ok(Value::Array(key)) if key.len() == orders.len() => Ok(key.0),
+		_ => Err(RpcError::InvalidParams),
+	}
+}
+
+/// Builds a condition which only matches rows sorted after the given key.
+///
+/// For `ORDER BY a, b DESC` this is `a > $a OR (a = $a AND b < $b)`.
+pub fn after(orders: &Orders, key: Vec<Value>) -> Value {
+	let binary = |l: Value, o: Operator, r: Value| {
+		Value::Expression(Box::new(Expression::Binary {
+			l,
+			o,
+			r,
+		}))
+	};
+	let mut cond: Option<Value> = None;
+	// Build the condition from the last sort field backwards
+	for (order, val) in orders.iter().zip(key).rev() {
+		let field = Value::Idiom(order.order.clone());
+		let o = match order.direction {
+			true => Operator::MoreThan,
+			false => Operator::LessThan,
+		};
+		let after = binary(field.clone(), o, val.clone());
+		cond = Some(match cond {
+			None => after,
+			Some(rest) => {
+				let tie = binary(binary(field, Operator::Equal, val), Operator::And, rest);
+				binary(after, Operator::Or, tie)
+			}
+		});
+	}
+	cond.unwrap_or(Value::Bool(true))
+}
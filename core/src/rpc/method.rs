@@ -14,6 +14,7 @@ pub enum Method {
 	Unset,
 	Select,
 	Insert,
+	InsertRelation,
 	Create,
 	Upsert,
 	Update,
@@ -25,6 +26,16 @@ pub enum Method {
 	Relate,
 	Run,
 	GraphQL,
+	Prepare,
+	ExecutePrepared,
+	Consistency,
+	Compute,
+	Capabilities,
+	ConnectionInfo,
+	Export,
+	Import,
+	Batch,
+	Health,
 }
 
 impl Method {
@@ -46,6 +57,7 @@ impl Method {
 			"unset" => Self::Unset,
 			"select" => Self::Select,
 			"insert" => Self::Insert,
+			"insert_relation" => Self::InsertRelation,
 			"create" => Self::Create,
 			"upsert" => Self::Upsert,
 			"update" => Self::Update,
@@ -57,6 +69,16 @@ impl Method {
 			"relate" => Self::Relate,
 			"run" => Self::Run,
 			"graphql" => Self::GraphQL,
+			"prepare" => Self::Prepare,
+			"execute_prepared" => Self::ExecutePrepared,
+			"consistency" => Self::Consistency,
+			"compute" => Self::Compute,
+			"capabilities" => Self::Capabilities,
+			"connection_info" => Self::ConnectionInfo,
+			"export" => Self::Export,
+			"import" => Self::Import,
+			"batch" => Self::Batch,
+			"health" => Self::Health,
 			_ => Self::Unknown,
 		}
 	}
@@ -79,6 +101,7 @@ impl Method {
 			Self::Unset => "unset",
 			Self::Select => "select",
 			Self::Insert => "insert",
+			Self::InsertRelation => "insert_relation",
 			Self::Create => "create",
 			Self::Upsert => "upsert",
 			Self::Update => "update",
@@ -90,6 +113,16 @@ impl Method {
 			Self::Relate => "relate",
 			Self::Run => "run",
 			Self::GraphQL => "graphql",
+			Self::Prepare => "prepare",
+			Self::ExecutePrepared => "execute_prepared",
+			Self::Consistency => "consistency",
+			Self::Compute => "compute",
+			Self::Capabilities => "capabilities",
+			Self::ConnectionInfo => "connection_info",
+			Self::Export => "export",
+			Self::Import => "import",
+			Self::Batch => "batch",
+			Self::Health => "health",
 		}
 	}
 }
@@ -115,6 +148,12 @@ impl Method {
 				| Method::Delete | Method::Version
 				| Method::Query | Method::Relate
 				| Method::Run | Method::GraphQL
+				| Method::ExecutePrepared | Method::Compute
+				| Method::Capabilities
+				| Method::ConnectionInfo
+				| Method::Export
+				| Method::InsertRelation
+				| Method::Health
 				| Method::Unknown
 		)
 	}
@@ -1,13 +1,16 @@
 pub mod args;
 pub mod basic_context;
+mod cursor;
 pub mod format;
 pub mod method;
+pub mod prepared;
 pub mod request;
 mod response;
 pub mod rpc_context;
 mod rpc_error;
 
 pub use basic_context::BasicRpcContext;
+pub use prepared::PreparedStatements;
 pub use response::Data;
 pub use rpc_context::RpcContext;
 pub use rpc_error::RpcError;
@@ -0,0 +1,77 @@
+use std::collections::{HashMap, VecDeque};
+
+use uuid::Uuid;
+
+use crate::cnf::RPC_PREPARED_STATEMENTS_LIMIT;
+use crate::sql::Query;
+
+/// A per-session store of parsed queries, referenced by a handle
+/// returned to the client when the query was prepared.
+#[derive(Debug)]
+pub struct PreparedStatements {
+	limit: usize,
+	queries: HashMap<Uuid, Query>,
+	order: VecDeque<Uuid>,
+}
+
+impl Default for PreparedStatements {
+	fn default() -> Self {
+		Self::new(*RPC_PREPARED_STATEMENTS_LIMIT)
+	}
+}
+
+impl PreparedStatements {
+	/// Create a store which holds at most `limit` prepared statements
+	pub fn new(limit: usize) -> Self {
+		Self {
+			limit,
+			queries: HashMap::new(),
+			order: VecDeque::new(),
+		}
+	}
+
+	/// Store a parsed query, evicting the oldest entry if the store is full
+	pub fn insert(&mut self, query: Query) -> Uuid {
+		while self.order.len() >= self.limit.max(1) {
+			if let Some(id) = self.order.pop_front() {
+				self.queries.remove(&id);
+			}
+		}
+		let id = Uuid::new_v4();
+		self.queries.insert(id, query);
+		self.order.push_back(id);
+		id
+	}
+
+	/// Fetch a previously prepared query
+	pub fn get(&self, id: &Uuid) -> Option<&Query> {
+		self.queries.get(id)
+	}
+
+	/// The number of prepared statements currently stored
+	pub fn len(&self) -> usize {
+		self.queries.len()
+	}
+
+	/// Check if there are no prepared statements stored
+	pub fn is_empty(&self) -> bool {
+		self.queries.is_empty()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn evicts_oldest() {
+		let mut store = PreparedStatements::new(2);
+		let a = store.insert(Query::default());
+		let b = store.insert(Query::default());
+		let c = store.insert(Query::default());
+		assert_eq!(store.len(), 2);
+		assert!(store.get(&a).is_none());
+		assert!(store.get(&b).is_some());
+		assert!(store.get(&c).is_some());
+	}
+}
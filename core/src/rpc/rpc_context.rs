@@ -1,19 +1,37 @@
-use std::{collections::BTreeMap, mem};
+use std::{collections::BTreeMap, fmt::Display, hash::Hash, mem};
 
 #[cfg(all(not(target_arch = "wasm32"), surrealdb_unstable))]
 use async_graphql::BatchRequest;
+use reblessive::Stack;
 use uuid::Uuid;
 
 #[cfg(all(not(target_arch = "wasm32"), surrealdb_unstable))]
 use crate::gql::SchemaCache;
 use crate::{
-	dbs::{QueryType, Response, Session},
-	kvs::Datastore,
+	cnf::{
+		FUNCTION_ALLOCATION_LIMIT, MAX_COMPUTATION_DEPTH, RPC_PREPARED_STATEMENTS_LIMIT,
+		RPC_STREAM_CHUNK_SIZE, TRANSACTION_CACHE_SIZE,
+	},
+	dbs::{capabilities::Targets, QueryType, Response, Session},
+	err::Error,
+	iam::{Action, ResourceKind},
+	kvs::{Consistency, Datastore, ExportConfig, LockType, TransactionType},
 	rpc::args::Take,
-	sql::{Array, Function, Model, Statement, Strand, Value},
+	sql::{
+		statements::{
+			BeginStatement, CommitStatement, CreateStatement, DeleteStatement, UpdateStatement,
+			UpsertStatement,
+		},
+		Array, Cond, Datetime, Expression, Function, Kind, Limit, Model, Number, Object, Operator,
+		Output, Query, Statement, Statements, Strand, Value, Values,
+	},
+	syn::parser::Parser,
 };
 
-use super::{method::Method, response::Data, rpc_error::RpcError};
+use super::{
+	cursor, method::Method, prepared::PreparedStatements, request::ID, response::Data,
+	rpc_error::RpcError,
+};
 
 #[allow(async_fn_in_trait)]
 pub trait RpcContext {
@@ -32,6 +50,22 @@ pub trait RpcContext {
 		async { unimplemented!("handle functions must be redefined if LQ_SUPPORT = true") }
 	}
 
+	const STREAM_SUPPORT: bool = false;
+	fn handle_stream(
+		&self,
+		_chunk: Value,
+	) -> impl std::future::Future<Output = Result<(), RpcError>> + Send {
+		async { Err(RpcError::BadStreamConfig) }
+	}
+
+	const PREPARE_SUPPORT: bool = false;
+	fn prepared_statements(&self) -> &PreparedStatements {
+		unimplemented!("prepared_statements must be implemented if PREPARE_SUPPORT = true")
+	}
+	fn prepared_statements_mut(&mut self) -> &mut PreparedStatements {
+		unimplemented!("prepared_statements_mut must be implemented if PREPARE_SUPPORT = true")
+	}
+
 	#[cfg(all(not(target_arch = "wasm32"), surrealdb_unstable))]
 	const GQL_SUPPORT: bool = false;
 
@@ -45,6 +79,9 @@ pub trait RpcContext {
 			Method::Ping => Ok(Value::None.into()),
 			Method::Info => self.info().await.map(Into::into).map_err(Into::into),
 			Method::Use => self.yuse(params).await.map(Into::into).map_err(Into::into),
+			Method::Consistency => {
+				self.consistency(params).await.map(Into::into).map_err(Into::into)
+			}
 			Method::Signup => self.signup(params).await.map(Into::into).map_err(Into::into),
 			Method::Signin => self.signin(params).await.map(Into::into).map_err(Into::into),
 			Method::Invalidate => self.invalidate().await.map(Into::into).map_err(Into::into),
@@ -57,6 +94,9 @@ pub trait RpcContext {
 			Method::Unset => self.unset(params).await.map(Into::into).map_err(Into::into),
 			Method::Select => self.select(params).await.map(Into::into).map_err(Into::into),
 			Method::Insert => self.insert(params).await.map(Into::into).map_err(Into::into),
+			Method::InsertRelation => {
+				self.insert_relation(params).await.map(Into::into).map_err(Into::into)
+			}
 			Method::Create => self.create(params).await.map(Into::into).map_err(Into::into),
 			Method::Upsert => self.upsert(params).await.map(Into::into).map_err(Into::into),
 			Method::Update => self.update(params).await.map(Into::into).map_err(Into::into),
@@ -68,6 +108,21 @@ pub trait RpcContext {
 			Method::Relate => self.relate(params).await.map(Into::into).map_err(Into::into),
 			Method::Run => self.run(params).await.map(Into::into).map_err(Into::into),
 			Method::GraphQL => self.graphql(params).await.map(Into::into).map_err(Into::into),
+			Method::Prepare => self.prepare(params).await.map(Into::into).map_err(Into::into),
+			Method::ExecutePrepared => {
+				self.execute_prepared(params).await.map(Into::into).map_err(Into::into)
+			}
+			Method::Compute => self.compute(params).await.map(Into::into).map_err(Into::into),
+			Method::Capabilities => {
+				self.capabilities(params).await.map(Into::into).map_err(Into::into)
+			}
+			Method::ConnectionInfo => {
+				self.connection_info(params).await.map(Into::into).map_err(Into::into)
+			}
+			Method::Export => self.export(params).await.map(Into::into).map_err(Into::into),
+			Method::Import => self.import(params).await.map(Into::into).map_err(Into::into),
+			Method::Batch => self.batch(params).await.map(Into::into).map_err(Into::into),
+			Method::Health => self.health(params).await.map(Into::into).map_err(Into::into),
 			Method::Unknown => Err(RpcError::MethodNotFound),
 		}
 	}
@@ -78,6 +133,9 @@ pub trait RpcContext {
 			Method::Info => self.info().await.map(Into::into).map_err(Into::into),
 			Method::Select => self.select(params).await.map(Into::into).map_err(Into::into),
 			Method::Insert => self.insert(params).await.map(Into::into).map_err(Into::into),
+			Method::InsertRelation => {
+				self.insert_relation(params).await.map(Into::into).map_err(Into::into)
+			}
 			Method::Create => self.create(params).await.map(Into::into).map_err(Into::into),
 			Method::Upsert => self.upsert(params).await.map(Into::into).map_err(Into::into),
 			Method::Update => self.update(params).await.map(Into::into).map_err(Into::into),
@@ -89,6 +147,18 @@ pub trait RpcContext {
 			Method::Relate => self.relate(params).await.map(Into::into).map_err(Into::into),
 			Method::Run => self.run(params).await.map(Into::into).map_err(Into::into),
 			Method::GraphQL => self.graphql(params).await.map(Into::into).map_err(Into::into),
+			Method::ExecutePrepared => {
+				self.execute_prepared(params).await.map(Into::into).map_err(Into::into)
+			}
+			Method::Compute => self.compute(params).await.map(Into::into).map_err(Into::into),
+			Method::Capabilities => {
+				self.capabilities(params).await.map(Into::into).map_err(Into::into)
+			}
+			Method::ConnectionInfo => {
+				self.connection_info(params).await.map(Into::into).map_err(Into::into)
+			}
+			Method::Export => self.export(params).await.map(Into::into).map_err(Into::into),
+			Method::Health => self.health(params).await.map(Into::into).map_err(Into::into),
 			Method::Unknown => Err(RpcError::MethodNotFound),
 			_ => Err(RpcError::MethodNotFound),
 		}
@@ -126,16 +196,52 @@ pub trait RpcContext {
 		Ok(Value::None)
 	}
 
+	async fn consistency(&mut self, params: Array) -> Result<impl Into<Data>, RpcError> {
+		let Ok(Value::Strand(level)) = params.needs_one() else {
+			return Err(RpcError::InvalidParams);
+		};
+		let consistency = match level.to_lowercase().as_str() {
+			"strong" => Consistency::Strong,
+			"eventual" => Consistency::Eventual,
+			_ => return Err(RpcError::InvalidParams),
+		};
+		// Check that the storage engine supports this level
+		self.kvs().check_consistency(consistency)?;
+		self.session_mut().cl = consistency;
+		Ok(Value::None)
+	}
+
 	async fn signup(&mut self, params: Array) -> Result<impl Into<Data>, RpcError> {
-		let Ok(Value::Object(v)) = params.needs_one() else {
+		let Ok((Value::Object(v), opts)) = params.needs_one_or_two() else {
 			return Err(RpcError::InvalidParams);
 		};
+		// The second parameter is an optional options object
+		let mut detailed = false;
+		match opts {
+			Value::Object(o) => {
+				for (k, v) in o {
+					match (k.as_str(), v) {
+						("detailed", Value::Bool(v)) => detailed = v,
+						_ => return Err(RpcError::InvalidParams),
+					}
+				}
+			}
+			Value::None | Value::Null => {}
+			_ => return Err(RpcError::InvalidParams),
+		}
 		let mut tmp_session = mem::take(self.session_mut());
 
 		let out: Result<Value, RpcError> =
 			crate::iam::signup::signup(self.kvs(), &mut tmp_session, v)
 				.await
-				.map(Into::into)
+				.map(|tk| match detailed {
+					// The session record is the one created by the signup query
+					true => Value::from(map! {
+						String::from("token") => Value::from(tk),
+						String::from("id") => tmp_session.rd.clone().unwrap_or_default(),
+					}),
+					false => tk.into(),
+				})
 				.map_err(Into::into);
 
 		*self.session_mut() = tmp_session;
@@ -224,35 +330,93 @@ pub trait RpcContext {
 
 	async fn kill(&mut self, params: Array) -> Result<impl Into<Data>, RpcError> {
 		let id = params.needs_one()?;
-		// Specify the SQL query string
-		let sql = "KILL $id";
-		// Specify the query parameters
-		let var = map! {
-			String::from("id") => id,
-			=> &self.vars()
+		// Kill a single live query
+		let Value::Array(ids) = id else {
+			return self.kill_inner(id).await;
 		};
-		// Execute the query on the database
-		// let mut res = self.query_with(Value::from(sql), Object::from(var)).await?;
-		let mut res = self.query_inner(Value::from(sql), Some(var)).await?;
-		// Extract the first query result
-		let response = res.remove(0);
-		response.result.map_err(Into::into)
+		// Kill each live query, reporting the outcome per id
+		let mut out = Array::with_capacity(ids.len());
+		for id in ids {
+			let (status, result) = match self.kill_inner(id.clone()).await {
+				Ok(v) => ("OK", v),
+				Err(RpcError::InternalError(e)) if matches!(e.root(), Error::LvNotFound { .. }) => {
+					("ERR", Value::from(e.to_string()))
+				}
+				Err(e) => return Err(e),
+			};
+			out.push(Value::from(map! {
+				String::from("id") => id,
+				String::from("status") => Value::from(status),
+				String::from("result") => result,
+			}));
+		}
+		Ok(Value::from(out))
 	}
 
 	async fn live(&mut self, params: Array) -> Result<impl Into<Data>, RpcError> {
-		let (tb, diff) = params.needs_one_or_two()?;
+		let (tb, diff, opts) = params.needs_one_two_or_three()?;
 		// Specify the SQL query string
 		let sql = match diff.is_true() {
 			true => "LIVE SELECT DIFF FROM $tb",
 			false => "LIVE SELECT * FROM $tb",
 		};
+		// The third parameter is an optional options object
+		let mut filter = None;
+		let sql = match opts {
+			Value::Object(mut o) => {
+				// Only notify for records which match the condition
+				match o.remove("filter") {
+					Some(Value::Strand(v)) => filter = Some(parse_expression(&v)?),
+					Some(_) => return Err(RpcError::InvalidParams),
+					None => (),
+				}
+				// Only notify for the specified actions
+				let sql = match o.remove("actions") {
+					Some(Value::Array(actions)) if !actions.is_empty() => {
+						let mut events = Vec::with_capacity(actions.len());
+						for action in actions {
+							let Value::Strand(action) = action else {
+								return Err(RpcError::InvalidParams);
+							};
+							match action.to_uppercase().as_str() {
+								v @ ("CREATE" | "UPDATE" | "DELETE") => {
+									events.push(format!("'{v}'"))
+								}
+								_ => return Err(RpcError::InvalidParams),
+							}
+						}
+						format!("{sql} WHERE $event IN [{}]", events.join(", "))
+					}
+					Some(_) => return Err(RpcError::InvalidParams),
+					None => sql.to_owned(),
+				};
+				if !o.is_empty() {
+					return Err(RpcError::InvalidParams);
+				}
+				sql
+			}
+			Value::None | Value::Null => sql.to_owned(),
+			_ => return Err(RpcError::InvalidParams),
+		};
+		// Combine the filter with any action condition
+		let mut query = crate::syn::parse(&sql)?;
+		if let (Some(filter), Some(Statement::Live(stm))) = (filter, query.0 .0.first_mut()) {
+			stm.cond = Some(Cond(match stm.cond.take() {
+				Some(Cond(cond)) => Value::Expression(Box::new(Expression::Binary {
+					l: cond,
+					o: Operator::And,
+					r: filter,
+				})),
+				None => filter,
+			}));
+		}
 		// Specify the query parameters
 		let var = map! {
 			String::from("tb") => tb.could_be_table(),
 			=> &self.vars()
 		};
 		// Execute the query on the database
-		let mut res = self.query_inner(Value::from(sql), Some(var)).await?;
+		let mut res = self.query_inner(Value::Query(query), Some(var)).await?;
 		// Extract the first query result
 		let response = res.remove(0);
 		response.result.map_err(Into::into)
@@ -263,24 +427,61 @@ pub trait RpcContext {
 	// ------------------------------
 
 	async fn select(&self, params: Array) -> Result<impl Into<Data>, RpcError> {
-		let Ok(what) = params.needs_one() else {
+		let Ok((what, opts)) = params.needs_one_or_two() else {
 			return Err(RpcError::InvalidParams);
 		};
+		// The second parameter is an optional options object
+		let mut only = false;
+		let mut limit = None;
+		let mut start = None;
+		match opts {
+			Value::Object(o) => {
+				for (k, v) in o {
+					match (k.as_str(), v) {
+						("only", Value::Bool(v)) => only = v,
+						("limit", Value::Number(Number::Int(v))) if v >= 0 => limit = Some(v),
+						("start", Value::Number(Number::Int(v))) if v >= 0 => start = Some(v),
+						_ => return Err(RpcError::InvalidParams),
+					}
+				}
+			}
+			Value::None | Value::Null => {}
+			_ => return Err(RpcError::InvalidParams),
+		}
+		// A record range selects many records
+		let what = could_be_range(what);
 		// Return a single result?
 		let one = what.is_thing();
 		// Specify the SQL query string
-		let sql = "SELECT * FROM $what";
+		let mut sql = String::from("SELECT * FROM $what");
+		// Only paginate when selecting more than a single record
+		if !one {
+			if limit.is_some() {
+				sql.push_str(" LIMIT $limit");
+			}
+			if start.is_some() {
+				sql.push_str(" START $start");
+			}
+		}
 		// Specify the query parameters
 		let var = Some(map! {
 			String::from("what") => what.could_be_table(),
+			String::from("limit"), if let Some(v) = limit => Value::from(v),
+			String::from("start"), if let Some(v) = start => Value::from(v),
 			=> &self.vars()
 		});
 		// Execute the query on the database
-		let mut res = self.kvs().execute(sql, self.session(), var).await?;
+		let mut res = self.kvs().execute(&sql, self.session(), var).await?;
 		// Extract the first query result
-		let res = match one {
-			true => res.remove(0).result?.first(),
-			false => res.remove(0).result?,
+		let res = match (one, res.remove(0).result?) {
+			(true, res) => res.first(),
+			// Enforce a single result like the ONLY keyword
+			(false, Value::Array(mut a)) if only => match a.len() {
+				0 => Value::None,
+				1 => a.remove(0),
+				_ => return Err(crate::err::Error::SingleOnlyOutput.into()),
+			},
+			(false, res) => res,
 		};
 		// Return the result to the client
 		Ok(res)
@@ -291,13 +492,72 @@ pub trait RpcContext {
 	// ------------------------------
 
 	async fn insert(&self, params: Array) -> Result<impl Into<Data>, RpcError> {
+		let Ok((what, data, opts)) = params.needs_two_or_three() else {
+			return Err(RpcError::InvalidParams);
+		};
+		// The third parameter is an optional options object
+		let mut output = "AFTER";
+		match opts {
+			Value::Object(o) => {
+				for (k, v) in o {
+					match (k.as_str(), v) {
+						("return", Value::Strand(s)) => {
+							output = match s.to_lowercase().as_str() {
+								"none" => "NONE",
+								"before" => "BEFORE",
+								"after" => "AFTER",
+								"diff" => "DIFF",
+								_ => return Err(RpcError::InvalidParams),
+							}
+						}
+						_ => return Err(RpcError::InvalidParams),
+					}
+				}
+			}
+			Value::None | Value::Null => {}
+			_ => return Err(RpcError::InvalidParams),
+		}
+		// Return a single result?
+		let one = what.is_thing();
+		// Specify the SQL query string
+		let sql = format!("INSERT INTO $what $data RETURN {output}");
+		// Specify the query parameters
+		let var = Some(map! {
+			String::from("what") => what.could_be_table(),
+			String::from("data") => data,
+			=> &self.vars()
+		});
+		// Execute the query on the database
+		let mut res = self.kvs().execute(&sql, self.session(), var).await?;
+		// Extract the first query result
+		let res = match one {
+			true => res.remove(0).result?.first(),
+			false => res.remove(0).result?,
+		};
+		// Return the result to the client
+		Ok(res)
+	}
+
+	async fn insert_relation(&self, params: Array) -> Result<impl Into<Data>, RpcError> {
 		let Ok((what, data)) = params.needs_two() else {
 			return Err(RpcError::InvalidParams);
 		};
+		// Every relation must specify the records which it connects
+		let edge = |v: &Value| match v {
+			Value::Object(o) => o.contains_key("in") && o.contains_key("out"),
+			_ => false,
+		};
+		let valid = match &data {
+			Value::Array(a) => a.iter().all(edge),
+			v => edge(v),
+		};
+		if !valid {
+			return Err(RpcError::InvalidParams);
+		}
 		// Return a single result?
 		let one = what.is_thing();
 		// Specify the SQL query string
-		let sql = "INSERT INTO $what $data RETURN AFTER";
+		let sql = "INSERT RELATION INTO $what $data RETURN AFTER";
 		// Specify the query parameters
 		let var = Some(map! {
 			String::from("what") => what.could_be_table(),
@@ -320,9 +580,11 @@ pub trait RpcContext {
 	// ------------------------------
 
 	async fn create(&self, params: Array) -> Result<impl Into<Data>, RpcError> {
-		let Ok((what, data)) = params.needs_one_or_two() else {
+		let Ok((what, data, opts)) = params.needs_one_two_or_three() else {
 			return Err(RpcError::InvalidParams);
 		};
+		// Clean the data according to the options
+		let data = sanitize(data, opts)?;
 		// Return a single result?
 		let one = what.is_thing();
 		// Specify the SQL query string
@@ -353,7 +615,7 @@ pub trait RpcContext {
 	// ------------------------------
 
 	async fn upsert(&self, params: Array) -> Result<impl Into<Data>, RpcError> {
-		let Ok((what, data)) = params.needs_one_or_two() else {
+		let Ok((what, data, merge)) = params.needs_one_two_or_three() else {
 			return Err(RpcError::InvalidParams);
 		};
 		// Return a single result?
@@ -361,6 +623,8 @@ pub trait RpcContext {
 		// Specify the SQL query string
 		let sql = if data.is_none_or_null() {
 			"UPSERT $what RETURN AFTER"
+		} else if merge.is_truthy() {
+			"UPSERT $what MERGE $data RETURN AFTER"
 		} else {
 			"UPSERT $what CONTENT $data RETURN AFTER"
 		};
@@ -386,9 +650,11 @@ pub trait RpcContext {
 	// ------------------------------
 
 	async fn update(&self, params: Array) -> Result<impl Into<Data>, RpcError> {
-		let Ok((what, data)) = params.needs_one_or_two() else {
+		let Ok((what, data, opts)) = params.needs_one_two_or_three() else {
 			return Err(RpcError::InvalidParams);
 		};
+		// Clean the data according to the options
+		let data = sanitize(data, opts)?;
 		// Return a single result?
 		let one = what.is_thing();
 		// Specify the SQL query string
@@ -422,6 +688,10 @@ pub trait RpcContext {
 		let Ok((what, data)) = params.needs_one_or_two() else {
 			return Err(RpcError::InvalidParams);
 		};
+		// Merge a batch of records at once?
+		if let Value::Array(data) = data {
+			return self.merge_many(what, data).await;
+		}
 		// Return a single result?
 		let one = what.is_thing();
 		// Specify the SQL query string
@@ -452,15 +722,39 @@ pub trait RpcContext {
 	// ------------------------------
 
 	async fn patch(&self, params: Array) -> Result<impl Into<Data>, RpcError> {
-		let Ok((what, data, diff)) = params.needs_one_two_or_three() else {
+		let Ok((what, data, opts)) = params.needs_one_two_or_three() else {
 			return Err(RpcError::InvalidParams);
 		};
+		// The third parameter is either the diff flag, or an options object
+		let (diff, merge_patch) = match opts {
+			Value::Object(o) => {
+				let mut diff = false;
+				let mut merge_patch = false;
+				for (k, v) in o {
+					match (k.as_str(), v) {
+						("diff", Value::Bool(b)) => diff = b,
+						("merge_patch", Value::Bool(b)) => merge_patch = b,
+						_ => return Err(RpcError::InvalidParams),
+					}
+				}
+				(diff, merge_patch)
+			}
+			v => (v.is_true(), false),
+		};
 		// Return a single result?
 		let one = what.is_thing();
 		// Specify the SQL query string
-		let sql = match diff.is_true() {
-			true => "UPDATE $what PATCH $data RETURN DIFF",
-			false => "UPDATE $what PATCH $data RETURN AFTER",
+		let (sql, data) = match merge_patch {
+			// A merge patch (RFC 7386) must be an object
+			true if !data.is_object() => return Err(RpcError::InvalidParams),
+			true => match diff {
+				true => ("UPDATE $what MERGE $data RETURN DIFF", Self::merge_patch(data)),
+				false => ("UPDATE $what MERGE $data RETURN AFTER", Self::merge_patch(data)),
+			},
+			false => match diff {
+				true => ("UPDATE $what PATCH $data RETURN DIFF", data),
+				false => ("UPDATE $what PATCH $data RETURN AFTER", data),
+			},
 		};
 		// Specify the query parameters
 		let var = Some(map! {
@@ -522,6 +816,8 @@ pub trait RpcContext {
 		let Ok(what) = params.needs_one() else {
 			return Err(RpcError::InvalidParams);
 		};
+		// A record range deletes many records
+		let what = could_be_range(what);
 		// Return a single result?
 		let one = what.is_thing();
 		// Specify the SQL query string
@@ -553,12 +849,254 @@ pub trait RpcContext {
 		}
 	}
 
+	async fn health(&self, params: Array) -> Result<impl Into<Data>, RpcError> {
+		if !params.is_empty() {
+			return Err(RpcError::InvalidParams);
+		}
+		// A minimal read transaction shows whether the storage engine can be reached
+		let txn = self.kvs().transaction(TransactionType::Read, LockType::Optimistic).await;
+		let status = match txn {
+			Ok(txn) => match txn.cancel().await {
+				Ok(_) => "ok",
+				Err(_) => "unavailable",
+			},
+			// This also covers a datastore without a storage engine
+			Err(_) => "unavailable",
+		};
+		Ok(Value::from(map! {
+			String::from("status") => Value::from(status),
+			String::from("storage") => Value::from(self.kvs().to_string()),
+		}))
+	}
+
+	async fn capabilities(&self, params: Array) -> Result<impl Into<Data>, RpcError> {
+		if !params.is_empty() {
+			return Err(RpcError::InvalidParams);
+		}
+		let caps = self.kvs().capabilities();
+		// Describe what the server allows, and the limits it enforces
+		Ok(Value::from(map! {
+			String::from("scripting") => Value::from(caps.allows_scripting()),
+			String::from("guest_access") => Value::from(caps.allows_guest_access()),
+			String::from("live_query_notifications") => Value::from(caps.allows_live_query_notifications()),
+			String::from("functions") => Value::from(map! {
+				String::from("allow") => targets(caps.allowed_functions()),
+				String::from("deny") => targets(caps.denied_functions()),
+			}),
+			String::from("network_targets") => Value::from(map! {
+				String::from("allow") => targets(caps.allowed_network_targets()),
+				String::from("deny") => targets(caps.denied_network_targets()),
+			}),
+			String::from("limits") => Value::from(map! {
+				String::from("max_computation_depth") => Value::from(*MAX_COMPUTATION_DEPTH),
+				String::from("function_allocation") => Value::from(*FUNCTION_ALLOCATION_LIMIT),
+				String::from("transaction_cache_size") => Value::from(*TRANSACTION_CACHE_SIZE),
+				String::from("prepared_statements") => Value::from(*RPC_PREPARED_STATEMENTS_LIMIT),
+			}),
+		}))
+	}
+
+	async fn connection_info(&self, params: Array) -> Result<impl Into<Data>, RpcError> {
+		if !params.is_empty() {
+			return Err(RpcError::InvalidParams);
+		}
+		let session = self.session();
+		let authenticated = !session.au.is_anon();
+		// Settings which are not set are returned as NONE
+		let opt = |v: &Option<String>| v.as_deref().map_or(Value::None, Value::from);
+		// An anonymous session does not expire
+		let expires_at = match session.exp {
+			Some(exp) if authenticated => {
+				Datetime::try_from((exp, 0)).map_or(Value::None, Value::from)
+			}
+			_ => Value::None,
+		};
+		Ok(Value::from(map! {
+			String::from("ns") => opt(&session.ns),
+			String::from("db") => opt(&session.db),
+			String::from("authenticated") => Value::from(authenticated),
+			String::from("user") => match authenticated {
+				true => Value::from(session.au.id()),
+				false => Value::None,
+			},
+			String::from("access") => opt(&session.ac),
+			String::from("expires_at") => expires_at,
+		}))
+	}
+
+	// ------------------------------
+	// Methods for exporting
+	// ------------------------------
+
+	async fn export(&self, params: Array) -> Result<impl Into<Data>, RpcError> {
+		if params.len() > 1 {
+			return Err(RpcError::InvalidParams);
+		}
+		// Only the configured parts of the database are exported
+		let mut cfg = ExportConfig::default();
+		match params.into_iter().next() {
+			Some(Value::Object(o)) => {
+				for (k, v) in o {
+					match (k.as_str(), v) {
+						("definitions", Value::Bool(v)) => cfg.definitions = v,
+						("records", Value::Bool(v)) => cfg.records = v,
+						("tables", Value::Array(v)) => {
+							let tables = v
+								.into_iter()
+								.map(|v| match v {
+									Value::Strand(v) => Ok(v.0),
+									_ => Err(RpcError::InvalidParams),
+								})
+								.collect::<Result<_, _>>()?;
+							cfg.tables = Some(tables);
+						}
+						_ => return Err(RpcError::InvalidParams),
+					}
+				}
+			}
+			None | Some(Value::None | Value::Null) => (),
+			_ => return Err(RpcError::InvalidParams),
+		}
+		// Ensure a namespace and database are selected
+		let (Some(ns), Some(db)) = (&self.session().ns, &self.session().db) else {
+			return Err(RpcError::InvalidParams);
+		};
+		// Check the permissions level
+		self.kvs().check(self.session(), Action::View, ResourceKind::Any.on_db(ns, db))?;
+		// The dump is returned as a single string, so it is collected in full
+		let (snd, rcv) = channel::unbounded();
+		self.kvs().export_with_config(self.session(), cfg, snd).await?.await?;
+		let mut out = Vec::new();
+		while let Ok(v) = rcv.try_recv() {
+			out.extend(v);
+		}
+		String::from_utf8(out).map(Value::from).map_err(|e| RpcError::Thrown(e.to_string()))
+	}
+
+	// ------------------------------
+	// Methods for importing
+	// ------------------------------
+
+	async fn import(&mut self, params: Array) -> Result<impl Into<Data>, RpcError> {
+		let Ok(Value::Strand(sql)) = params.needs_one() else {
+			return Err(RpcError::InvalidParams);
+		};
+		let query = crate::syn::parse(&sql)?;
+		// Live queries can only be started where notifications can be delivered
+		if !Self::LQ_SUPPORT && query.iter().any(|s| matches!(s, Statement::Live(_))) {
+			return Err(RpcError::BadLQConfig);
+		}
+		// Run every statement, reporting the outcome of each one
+		let res = self.kvs().process(query, self.session(), None).await?;
+		let res: Vec<Value> = res
+			.into_iter()
+			.map(|r| {
+				let time = r.speed();
+				let (status, result) = match r.result {
+					Ok(v) => ("OK", v),
+					Err(e) => ("ERR", Value::from(e.to_string())),
+				};
+				Value::from(map! {
+					String::from("time") => Value::from(time),
+					String::from("status") => Value::from(status),
+					String::from("result") => result,
+				})
+			})
+			.collect();
+		Ok(Value::from(res))
+	}
+
+	// ------------------------------
+	// Methods for batching
+	// ------------------------------
+
+	async fn batch(&mut self, params: Array) -> Result<impl Into<Data>, RpcError> {
+		let Ok(Value::Array(ops)) = params.needs_one() else {
+			return Err(RpcError::InvalidParams);
+		};
+		// Build a single transaction from all of the operations
+		let mut stms = Vec::with_capacity(ops.len() + 2);
+		let mut ones = Vec::with_capacity(ops.len());
+		stms.push(Statement::Begin(BeginStatement));
+		for op in ops {
+			let Value::Object(mut op) = op else {
+				return Err(RpcError::InvalidParams);
+			};
+			let method = match op.remove("method") {
+				Some(Value::Strand(v)) => v.0,
+				_ => return Err(RpcError::InvalidParams),
+			};
+			let params = match op.remove("params") {
+				Some(Value::Array(v)) => v,
+				None => Array::new(),
+				_ => return Err(RpcError::InvalidParams),
+			};
+			if !op.is_empty() {
+				return Err(RpcError::InvalidParams);
+			}
+			let (stm, one) = batch_operation(&method, params)?;
+			stms.push(stm);
+			ones.push(one);
+		}
+		stms.push(Statement::Commit(CommitStatement));
+		// Execute the transaction on the database
+		let ast = Query(Statements(stms));
+		let var = Some(self.vars().clone());
+		let mut res = self.kvs().process(ast, self.session(), var).await?;
+		// Report the operation which caused the transaction to fail
+		let skipped =
+			|e: &Error| matches!(e, Error::QueryNotExecuted | Error::QueryNotExecutedDetail { .. });
+		let cause = res.iter().position(|r| r.result.as_ref().is_err_and(|e| !skipped(e)));
+		// Otherwise the transaction itself failed to commit
+		let cause = cause.or_else(|| res.iter().position(|r| r.result.is_err()));
+		if let Some(index) = cause {
+			if let Err(error) = res.swap_remove(index).result {
+				return Err(RpcError::BatchFailed {
+					index,
+					error,
+				});
+			}
+		}
+		// Return the results of each operation in order
+		let res: Vec<Value> = res
+			.into_iter()
+			.zip(ones)
+			.map(|(r, one)| match one {
+				true => r.result.unwrap_or_default().first(),
+				false => r.result.unwrap_or_default(),
+			})
+			.collect();
+		Ok(Value::from(res))
+	}
+
 	// ------------------------------
 	// Methods for querying
 	// ------------------------------
 
-	async fn query(&self, params: Array) -> Result<impl Into<Data>, RpcError> {
-		let Ok((query, o)) = params.needs_one_or_two() else {
+	async fn compute(&self, params: Array) -> Result<impl Into<Data>, RpcError> {
+		let Ok((Value::Strand(expr), o)) = params.needs_one_or_two() else {
+			return Err(RpcError::InvalidParams);
+		};
+		let o = match o {
+			Value::Object(v) => Some(v),
+			Value::None | Value::Null => None,
+			_ => return Err(RpcError::InvalidParams),
+		};
+		// Parse the expression, which must not contain any statements
+		let val = parse_expression(&expr)?;
+		// Specify the query parameters
+		let vars = match o {
+			Some(mut v) => Some(mrg! {v.0, &self.vars()}),
+			None => Some(self.vars().clone()),
+		};
+		// Compute the expression on the database
+		let res = self.kvs().compute(val, self.session(), vars).await?;
+		// Return the result to the client
+		Ok(res)
+	}
+
+	async fn query(&self, params: Array) -> Result<Data, RpcError> {
+		let Ok((query, o, page)) = params.needs_one_two_or_three() else {
 			return Err(RpcError::InvalidParams);
 		};
 		if !(query.is_query() || query.is_strand()) {
@@ -571,12 +1109,76 @@ pub trait RpcContext {
 			_ => return Err(RpcError::InvalidParams),
 		};
 
+		// Specify the query parameters
+		let mut vars = match o {
+			Some(mut v) => mrg! {v.0, &self.vars()},
+			None => self.vars().clone(),
+		};
+		// The third parameter is an optional options object
+		let page = match page {
+			Value::Object(mut page) => {
+				// Convert any typed parameters before execution
+				match page.remove("types") {
+					Some(Value::Object(types)) => convert_vars(&mut vars, types)?,
+					Some(_) => return Err(RpcError::InvalidParams),
+					None => {}
+				}
+				// Any remaining options are used for pagination
+				Some(page).filter(|v| !v.is_empty())
+			}
+			Value::None | Value::Null => None,
+			_ => return Err(RpcError::InvalidParams),
+		};
+		match page {
+			Some(page) => self.query_page(query, Some(vars), page).await.map(Into::into),
+			None => self.query_inner(query, Some(vars)).await.map(Into::into),
+		}
+	}
+
+	// ------------------------------
+	// Methods for prepared statements
+	// ------------------------------
+
+	async fn prepare(&mut self, params: Array) -> Result<impl Into<Data>, RpcError> {
+		// If no prepared statement store then preparing is not possible
+		if !Self::PREPARE_SUPPORT {
+			return Err(RpcError::BadPrepareConfig);
+		}
+		let Ok(Value::Strand(sql)) = params.needs_one() else {
+			return Err(RpcError::InvalidParams);
+		};
+		// Parse the query once, so it can be executed many times
+		let query = crate::syn::parse(&sql)?;
+		let id = self.prepared_statements_mut().insert(query);
+		Ok(Value::Uuid(id.into()))
+	}
+
+	async fn execute_prepared(&self, params: Array) -> Result<impl Into<Data>, RpcError> {
+		// If no prepared statement store then executing is not possible
+		if !Self::PREPARE_SUPPORT {
+			return Err(RpcError::BadPrepareConfig);
+		}
+		let Ok((id, o)) = params.needs_one_or_two() else {
+			return Err(RpcError::InvalidParams);
+		};
+		let id = match id {
+			Value::Uuid(v) => v.0,
+			Value::Strand(v) => match Uuid::try_parse(&v) {
+				Ok(v) => v,
+				Err(_) => return Err(RpcError::InvalidParams),
+			},
+			_ => return Err(RpcError::InvalidParams),
+		};
+		let Some(query) = self.prepared_statements().get(&id).cloned() else {
+			return Err(RpcError::PreparedNotFound(id.to_string()));
+		};
 		// Specify the query parameters
 		let vars = match o {
-			Some(mut v) => Some(mrg! {v.0, &self.vars()}),
-			None => Some(self.vars().clone()),
+			Value::Object(mut v) => Some(mrg! {v.0, &self.vars()}),
+			Value::None | Value::Null => Some(self.vars().clone()),
+			_ => return Err(RpcError::InvalidParams),
 		};
-		self.query_inner(query, vars).await
+		self.query_inner(Value::Query(query), vars).await
 	}
 
 	// ------------------------------
@@ -584,7 +1186,8 @@ pub trait RpcContext {
 	// ------------------------------
 
 	async fn run(&self, params: Array) -> Result<impl Into<Data>, RpcError> {
-		let Ok((Value::Strand(Strand(func_name)), version, args)) = params.needs_one_two_or_three()
+		let Ok((Value::Strand(Strand(func_name)), version, args, opts)) =
+			params.needs_one_two_three_or_four()
 		else {
 			return Err(RpcError::InvalidParams);
 		};
@@ -597,10 +1200,44 @@ pub trait RpcContext {
 
 		let args = match args {
 			Value::Array(Array(arr)) => arr,
+			// Named arguments are only supported for custom functions
+			Value::Object(obj) => match func_name.strip_prefix("fn::") {
+				Some(name) => self.named_arguments(name, obj).await?,
+				None => return Err(RpcError::InvalidParams),
+			},
 			Value::None | Value::Null => vec![],
 			_ => return Err(RpcError::InvalidParams),
 		};
 
+		let mut stream = false;
+		let mut describe = false;
+		match opts {
+			Value::Object(o) => {
+				for (k, v) in o {
+					match (k.as_str(), v) {
+						("stream", Value::Bool(b)) => stream = b,
+						("describe", Value::Bool(b)) => describe = b,
+						_ => return Err(RpcError::InvalidParams),
+					}
+				}
+			}
+			Value::None | Value::Null => {}
+			_ => return Err(RpcError::InvalidParams),
+		}
+
+		// If no stream handler then streaming is not possible
+		if stream && !Self::STREAM_SUPPORT {
+			return Err(RpcError::BadStreamConfig);
+		}
+
+		// Return the signature of the function instead of running it
+		if describe {
+			return match func_name.strip_prefix("fn::") {
+				Some(name) => self.describe_function(name).await,
+				None => Err(RpcError::InvalidParams),
+			};
+		}
+
 		let func: Value = match &func_name[0..4] {
 			"fn::" => Function::Custom(func_name.chars().skip(4).collect(), args).into(),
 			"ml::" => Model {
@@ -616,7 +1253,68 @@ pub trait RpcContext {
 			.kvs()
 			.process(Statement::Value(func).into(), self.session(), Some(self.vars().clone()))
 			.await?;
-		res.remove(0).result.map_err(Into::into)
+		match res.remove(0).result? {
+			// Send array results back to the client in chunks
+			Value::Array(arr) if stream => self.send_in_chunks(arr).await,
+			res => Ok(res),
+		}
+	}
+
+	async fn describe_function(&self, name: &str) -> Result<Value, RpcError> {
+		// Ensure a NS and DB are set
+		let (Some(ns), Some(db)) = (&self.session().ns, &self.session().db) else {
+			return Err(RpcError::InvalidParams);
+		};
+		// Check the permissions level
+		self.kvs().check(self.session(), Action::View, ResourceKind::Function.on_db(ns, db))?;
+		// Fetch the function definition
+		let tx = self.kvs().transaction(TransactionType::Read, LockType::Optimistic).await?;
+		let res = tx.get_db_function(ns, db, name).await;
+		tx.cancel().await?;
+		let def = res?;
+		// Describe the declared signature
+		let args = def
+			.args
+			.iter()
+			.map(|(name, kind)| {
+				Value::from(map! {
+					String::from("name") => Value::from(name.to_raw()),
+					String::from("kind") => Value::from(kind.to_string()),
+				})
+			})
+			.collect::<Vec<_>>();
+		Ok(Value::from(map! {
+			String::from("name") => Value::from(format!("fn::{}", def.name.to_raw())),
+			String::from("args") => Value::from(args),
+			String::from("returns") => def.returns.as_ref().map(|k| Value::from(k.to_string())).unwrap_or_default(),
+			String::from("comment") => def.comment.clone().map(Value::from).unwrap_or_default(),
+		}))
+	}
+
+	async fn named_arguments(&self, name: &str, mut args: Object) -> Result<Vec<Value>, RpcError> {
+		// Ensure a NS and DB are set
+		let (Some(ns), Some(db)) = (&self.session().ns, &self.session().db) else {
+			return Err(RpcError::InvalidParams);
+		};
+		// Fetch the function definition
+		let tx = self.kvs().transaction(TransactionType::Read, LockType::Optimistic).await?;
+		let res = tx.get_db_function(ns, db, name).await;
+		tx.cancel().await?;
+		let def = res?;
+		// Order the values as the parameters are declared
+		let mut out = Vec::with_capacity(def.args.len());
+		for (name, kind) in def.args.iter() {
+			match args.remove(name.as_str()) {
+				Some(v) => out.push(v),
+				None if matches!(kind, Kind::Option(_)) => out.push(Value::None),
+				None => return Err(RpcError::InvalidParams),
+			}
+		}
+		// Any remaining values do not match a parameter
+		if !args.is_empty() {
+			return Err(RpcError::InvalidParams);
+		}
+		Ok(out)
 	}
 
 	// ------------------------------
@@ -677,8 +1375,18 @@ pub trait RpcContext {
 						serde_json::from_str(s.as_str()).map_err(|_| RpcError::ParseError)?;
 					tmp.into_single().map_err(|_| RpcError::ParseError)?
 				}
+				GraphQLFormat::Cbor => return Err(RpcError::InvalidParams),
+			},
+			Value::Bytes(b) => match format {
+				GraphQLFormat::Json => {
+					let tmp: BatchRequest =
+						serde_json::from_slice(&b).map_err(|_| RpcError::ParseError)?;
+					tmp.into_single().map_err(|_| RpcError::ParseError)?
+				}
 				GraphQLFormat::Cbor => {
-					return Err(RpcError::Thrown("Cbor is not yet supported".to_string()))
+					let tmp: BatchRequest =
+						ciborium::from_reader(b.as_slice()).map_err(|_| RpcError::ParseError)?;
+					tmp.into_single().map_err(|_| RpcError::ParseError)?
 				}
 			},
 			Value::Object(mut o) => {
@@ -717,6 +1425,14 @@ pub trait RpcContext {
 
 		let res = schema.execute(req).await;
 
+		// The response is encoded in the same format as the request
+		if let GraphQLFormat::Cbor = format {
+			let mut out = Vec::new();
+			ciborium::into_writer(&res, &mut out)
+				.map_err(|_| RpcError::Thrown("Serialization Error".to_string()))?;
+			return Ok(Value::Bytes(out.into()));
+		}
+
 		let out = match pretty {
 			true => {
 				let mut buf = Vec::new();
@@ -736,6 +1452,21 @@ pub trait RpcContext {
 	// Private methods
 	// ------------------------------
 
+	async fn kill_inner(&self, id: Value) -> Result<Value, RpcError> {
+		// Specify the SQL query string
+		let sql = "KILL $id";
+		// Specify the query parameters
+		let var = map! {
+			String::from("id") => id,
+			=> &self.vars()
+		};
+		// Execute the query on the database
+		let mut res = self.query_inner(Value::from(sql), Some(var)).await?;
+		// Extract the first query result
+		let response = res.remove(0);
+		response.result.map_err(Into::into)
+	}
+
 	async fn query_inner(
 		&self,
 		query: Value,
@@ -761,6 +1492,180 @@ pub trait RpcContext {
 		Ok(res)
 	}
 
+	async fn query_page(
+		&self,
+		query: Value,
+		vars: Option<BTreeMap<String, Value>>,
+		page: Object,
+	) -> Result<Value, RpcError> {
+		// Parse the pagination options
+		let mut position = None;
+		let mut limit = None;
+		for (k, v) in page {
+			match (k.as_str(), v) {
+				("cursor", Value::Strand(v)) => position = Some(v.0),
+				("cursor", Value::None | Value::Null) => {}
+				("limit", Value::Number(Number::Int(v))) if v > 0 => limit = Some(v),
+				_ => return Err(RpcError::InvalidParams),
+			}
+		}
+		let Some(limit) = limit else {
+			return Err(RpcError::InvalidParams);
+		};
+		// Only a single ordered SELECT statement can be paginated
+		let mut query = match query {
+			Value::Query(v) => v,
+			Value::Strand(v) => crate::syn::parse(&v)?,
+			_ => unreachable!(),
+		};
+		let mut stm = match query.0 .0.pop() {
+			Some(Statement::Select(v)) if query.0 .0.is_empty() => v,
+			_ => return Err(RpcError::InvalidParams),
+		};
+		// The sort order must be comparable using the sort key alone
+		let orders = match stm.order.clone() {
+			Some(v) if !v.is_empty() && v.iter().all(|o| !(o.random || o.collate || o.numeric)) => {
+				v
+			}
+			_ => return Err(RpcError::InvalidParams),
+		};
+		// The rows must be objects, and the page is selected using only the sort order
+		if stm.expr.1 || stm.limit.is_some() || stm.start.is_some() || stm.group.is_some() {
+			return Err(RpcError::InvalidParams);
+		}
+		// Rows which share a sort key are ordered by their record id
+		let orders = cursor::with_id(orders);
+		if !orders.iter().all(|o| cursor::is_projected(&stm.expr, stm.omit.as_ref(), &o.order)) {
+			return Err(RpcError::InvalidParams);
+		}
+		stm.order = Some(orders.clone());
+		// Continue after the last row of the previous page
+		if let Some(position) = position {
+			let after = cursor::after(&orders, cursor::decode(&orders, &position)?);
+			stm.cond = Some(Cond(match stm.cond.take() {
+				Some(Cond(cond)) => Value::Expression(Box::new(Expression::Binary {
+					l: cond,
+					o: Operator::And,
+					r: after,
+				})),
+				None => after,
+			}));
+		}
+		stm.limit = Some(Limit(Value::from(limit)));
+		// Execute the query on the database
+		let query = Query(Statements(vec![Statement::Select(stm)]));
+		let mut res = self.kvs().process(query, self.session(), vars).await?;
+		let rows = match res.remove(0).result? {
+			Value::Array(v) => v,
+			_ => return Err(RpcError::InvalidParams),
+		};
+		// Only return a cursor if there may be more rows
+		let next = match rows.last() {
+			Some(row) if rows.len() as i64 == limit => Value::from(cursor::encode(&orders, row)?),
+			_ => Value::None,
+		};
+		// Return the page to the client
+		Ok(Value::from(map! {
+			String::from("rows") => Value::Array(rows),
+			String::from("next_cursor") => next,
+		}))
+	}
+
+	async fn merge_many(&self, what: Value, data: Array) -> Result<Value, RpcError> {
+		// The records must belong to a table
+		let Value::Table(tb) = what.could_be_table() else {
+			return Err(RpcError::InvalidParams);
+		};
+		// Each entry must specify the record it applies to
+		let mut stms = Vec::with_capacity(data.len() + 2);
+		stms.push(Statement::Begin(BeginStatement));
+		for v in data {
+			let id = match v.pick(&*ID) {
+				Value::Thing(id) if id.tb == tb.0 => id,
+				_ => return Err(RpcError::InvalidParams),
+			};
+			stms.push(Statement::Update(UpdateStatement {
+				only: true,
+				what: Values(vec![Value::Thing(id)]),
+				data: Some(crate::sql::Data::MergeExpression(v)),
+				output: Some(Output::After),
+				..Default::default()
+			}));
+		}
+		stms.push(Statement::Commit(CommitStatement));
+		// Merge every record within a single transaction
+		let ast = Query(Statements(stms));
+		let var = Some(self.vars().clone());
+		let res = self.kvs().process(ast, self.session(), var).await?;
+		// Report the error which caused the transaction to fail
+		let skipped =
+			|e: &Error| matches!(e, Error::QueryNotExecuted | Error::QueryNotExecutedDetail { .. });
+		let mut out = Vec::with_capacity(res.len());
+		let mut failed = None;
+		for r in res {
+			match r.result {
+				Ok(v) => out.push(v),
+				Err(e) => match failed {
+					Some(ref f) if !skipped(f) => (),
+					_ => failed = Some(e),
+				},
+			}
+		}
+		if let Some(e) = failed {
+			return Err(e.into());
+		}
+		// Return the result to the client
+		Ok(Value::from(out))
+	}
+
+	/// Converts a JSON Merge Patch (RFC 7386) into a value which can be
+	/// merged into a record, where null values remove the field entirely.
+	fn merge_patch(data: Value) -> Value {
+		match data {
+			Value::Object(o) => Value::Object(
+				o.0.into_iter()
+					.map(|(k, v)| match v {
+						Value::Null => (k, Value::None),
+						v => (k, Self::merge_patch(v)),
+					})
+					.collect::<BTreeMap<_, _>>()
+					.into(),
+			),
+			v => v,
+		}
+	}
+
+	/// Sends an already computed array result to the client in chunks
+	///
+	/// The whole result is computed before any chunk is sent, so this does not reduce
+	/// the memory used by the server. It only splits a large response into a number of
+	/// smaller messages, which are followed by a summary of the chunks which were sent.
+	async fn send_in_chunks(&self, array: Array) -> Result<Value, RpcError> {
+		// Each chunk is tagged with the id of the stream
+		let id = Uuid::new_v4();
+		let count = array.len();
+		let mut values = array.into_iter().peekable();
+		let mut chunks = 0;
+		while values.peek().is_some() {
+			let chunk: Array =
+				values.by_ref().take(*RPC_STREAM_CHUNK_SIZE).collect::<Vec<_>>().into();
+			// Send the chunk to the client
+			self.handle_stream(Value::from(map! {
+				String::from("stream") => Value::Uuid(id.into()),
+				String::from("index") => Value::from(chunks),
+				String::from("result") => Value::from(chunk),
+			}))
+			.await?;
+			chunks += 1;
+		}
+		// Describe the stream once every chunk has been sent
+		Ok(Value::from(map! {
+			String::from("stream") => Value::Uuid(id.into()),
+			String::from("chunks") => Value::from(chunks),
+			String::from("count") => Value::from(count),
+		}))
+	}
+
 	async fn handle_live_query_results(&self, res: &Response) {
 		match &res.query_type {
 			QueryType::Live => {
@@ -777,3 +1682,183 @@ pub trait RpcContext {
 		}
 	}
 }
+
+/// Describes a set of capability targets, as either all, none, or a list of the targets
+fn targets<T: Hash + Eq + Display>(targets: &Targets<T>) -> Value {
+	match targets {
+		Targets::All => Value::Bool(true),
+		Targets::None => Value::Bool(false),
+		Targets::Some(v) => {
+			let mut v: Vec<String> = v.iter().map(ToString::to_string).collect();
+			v.sort();
+			v.into()
+		}
+	}
+}
+
+/// Converts a batch operation into a statement, and whether it returns a single record
+fn batch_operation(method: &str, params: Array) -> Result<(Statement, bool), RpcError> {
+	let Ok((what, data)) = params.needs_one_or_two() else {
+		return Err(RpcError::InvalidParams);
+	};
+	let one = what.is_thing();
+	let what = Values(vec![what.could_be_table()]);
+	let stm = match (method, data) {
+		("create", data) => Statement::Create(CreateStatement {
+			what,
+			data: content(data),
+			output: Some(Output::After),
+			..Default::default()
+		}),
+		("update", data) => Statement::Update(UpdateStatement {
+			what,
+			data: content(data),
+			output: Some(Output::After),
+			..Default::default()
+		}),
+		("upsert", data) => Statement::Upsert(UpsertStatement {
+			what,
+			data: content(data),
+			output: Some(Output::After),
+			..Default::default()
+		}),
+		("merge", data) if !data.is_none_or_null() => Statement::Update(UpdateStatement {
+			what,
+			data: Some(crate::sql::Data::MergeExpression(data)),
+			output: Some(Output::After),
+			..Default::default()
+		}),
+		("delete", Value::None | Value::Null) => Statement::Delete(DeleteStatement {
+			what,
+			output: Some(Output::Before),
+			..Default::default()
+		}),
+		_ => return Err(RpcError::InvalidParams),
+	};
+	Ok((stm, one))
+}
+
+/// Uses the data of a batch operation as the content of the records, if any was given
+fn content(data: Value) -> Option<crate::sql::Data> {
+	match data {
+		Value::None | Value::Null => None,
+		data => Some(crate::sql::Data::ContentExpression(data)),
+	}
+}
+
+/// Converts the query parameters to the types given in the `types` option of the query method
+fn convert_vars(vars: &mut BTreeMap<String, Value>, types: Object) -> Result<(), RpcError> {
+	for (name, kind) in types {
+		let Value::Strand(kind) = kind else {
+			return Err(RpcError::InvalidParams);
+		};
+		let kind = crate::syn::kind(&kind)?;
+		let val = vars.remove(&name).unwrap_or_default();
+		let val = val.convert_to(&kind).map_err(|e| e.set_check_from_convert(name.clone()))?;
+		vars.insert(name, val);
+	}
+	Ok(())
+}
+
+/// Applies the `trim` option of the create and update methods to the data
+fn sanitize(data: Value, opts: Value) -> Result<Value, RpcError> {
+	match opts {
+		Value::Object(mut o) => {
+			let trim = match o.remove("trim") {
+				Some(Value::Bool(v)) => v,
+				None => false,
+				_ => return Err(RpcError::InvalidParams),
+			};
+			if !o.is_empty() {
+				return Err(RpcError::InvalidParams);
+			}
+			Ok(match trim {
+				true => data.sanitize_strings(|s| s.trim().to_owned()),
+				false => data,
+			})
+		}
+		Value::None | Value::Null => Ok(data),
+		_ => Err(RpcError::InvalidParams),
+	}
+}
+
+/// Converts a record range which was sent as a string, such as `person:1..100`, into a range
+fn could_be_range(what: Value) -> Value {
+	match what {
+		Value::Strand(v) => match parse_expression(&v) {
+			Ok(range @ Value::Range(_)) => range,
+			_ => Value::Strand(v),
+		},
+		what => what,
+	}
+}
+
+/// Parses an untrusted expression sent by a client, such as a live query filter
+///
+/// Statements are not allowed anywhere within the expression, so that evaluating
+/// it can never read from or write to the database through a subquery.
+fn parse_expression(input: &str) -> Result<Value, RpcError> {
+	let mut parser = Parser::new(input.as_bytes()).with_no_subqueries(true);
+	let mut stack = Stack::new();
+	stack
+		.enter(|stk| parser.parse_expression_only(stk))
+		.finish()
+		.map_err(|_| RpcError::InvalidParams)
+}
+
+#[cfg(all(test, feature = "kv-mem"))]
+mod tests {
+	use super::*;
+
+	/// A context which supports streaming, but does not redefine the stream handler
+	struct StreamContext {
+		kvs: Datastore,
+		session: Session,
+		vars: BTreeMap<String, Value>,
+	}
+
+	impl RpcContext for StreamContext {
+		fn kvs(&self) -> &Datastore {
+			&self.kvs
+		}
+
+		fn session(&self) -> &Session {
+			&self.session
+		}
+
+		fn session_mut(&mut self) -> &mut Session {
+			&mut self.session
+		}
+
+		fn vars(&self) -> &BTreeMap<String, Value> {
+			&self.vars
+		}
+
+		fn vars_mut(&mut self) -> &mut BTreeMap<String, Value> {
+			&mut self.vars
+		}
+
+		fn version_data(&self) -> impl Into<Data> {
+			Value::None
+		}
+
+		const STREAM_SUPPORT: bool = true;
+	}
+
+	#[tokio::test]
+	async fn run_stream_without_handler() {
+		let mut ctx = StreamContext {
+			kvs: Datastore::new("memory").await.unwrap(),
+			session: Session::owner().with_ns("test").with_db("test"),
+			vars: BTreeMap::new(),
+		};
+		let params = Array::from(vec![
+			Value::from("array::range"),
+			Value::None,
+			Value::from(vec![Value::from(1), Value::from(10)]),
+			Value::from(map! { String::from("stream") => Value::Bool(true) }),
+		]);
+		let res = ctx.execute(Method::Run, params).await;
+		assert!(matches!(res, Err(RpcError::BadStreamConfig)), "{res:?}");
+	}
+}
@@ -21,6 +21,17 @@ pub enum RpcError {
 	BadLQConfig,
 	#[error("A GraphQL request was made, but GraphQL is not supported by the context")]
 	BadGQLConfig,
+	#[error("A streamed response was requested, but streaming is not supported by the context")]
+	BadStreamConfig,
+	#[error("A prepared statement was requested, but prepared statements are not supported by the context")]
+	BadPrepareConfig,
+	#[error("There is no prepared statement with the handle '{0}'")]
+	PreparedNotFound(String),
+	#[error("The batch operation at index {index} failed: {error}")]
+	BatchFailed {
+		index: usize,
+		error: err::Error,
+	},
 	#[error("Error: {0}")]
 	Thrown(String),
 }
@@ -28,7 +39,7 @@ pub enum RpcError {
 impl From<err::Error> for RpcError {
 	fn from(e: err::Error) -> Self {
 		use err::Error;
-		match e {
+		match e.root() {
 			Error::RealtimeDisabled => RpcError::LqNotSuported,
 			_ => RpcError::InternalError(e),
 		}
@@ -531,11 +531,44 @@ impl Number {
 	pub fn sign(self) -> Self {
 		match self {
 			Number::Int(n) => n.signum().into(),
-			Number::Float(n) => n.signum().into(),
+			// Unlike f64::signum, zero has no sign
+			Number::Float(n) => if n == 0.0 {
+				n
+			} else {
+				n.signum()
+			}
+			.into(),
 			Number::Decimal(n) => n.signum().into(),
 		}
 	}
 
+	pub fn is_sign_negative(&self) -> bool {
+		match self {
+			Number::Int(v) => *v < 0,
+			Number::Float(v) => v.is_sign_negative(),
+			Number::Decimal(v) => v.is_sign_negative(),
+		}
+	}
+
+	pub fn copysign(self, sign: Self) -> Self {
+		match self {
+			Number::Int(v) if (v < 0) == sign.is_sign_negative() => v.into(),
+			Number::Int(v) => match v.checked_neg() {
+				Some(v) => v.into(),
+				None => (-(v as f64)).into(),
+			},
+			Number::Float(v) => v.copysign(sign.to_float()).into(),
+			Number::Decimal(mut v) => {
+				v.set_sign_negative(sign.is_sign_negative());
+				v.into()
+			}
+		}
+	}
+
+	pub fn hypot(self, other: Self) -> Self {
+		self.to_float().hypot(other.to_float()).into()
+	}
+
 	pub fn sin(self) -> Self {
 		self.to_float().sin().into()
 	}
@@ -9,7 +9,7 @@ use crate::sql::{
 	statements::{
 		AlterStatement, AnalyzeStatement, BeginStatement, BreakStatement, CancelStatement,
 		CommitStatement, ContinueStatement, CreateStatement, DefineStatement, DeleteStatement,
-		ForeachStatement, IfelseStatement, InfoStatement, InsertStatement, KillStatement,
+		ExplainStatement, ForeachStatement, IfelseStatement, InfoStatement, InsertStatement, KillStatement,
 		LiveStatement, OptionStatement, OutputStatement, RelateStatement, RemoveStatement,
 		SelectStatement, SetStatement, ShowStatement, SleepStatement, ThrowStatement,
 		UpdateStatement, UpsertStatement, UseStatement,
@@ -56,7 +56,7 @@ impl Display for Statements {
 	}
 }
 
-#[revisioned(revision = 5)]
+#[revisioned(revision = 6)]
 #[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Store, Hash)]
 #[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
 #[non_exhaustive]
@@ -98,6 +98,8 @@ pub enum Statement {
 	#[doc(hidden)]
 	#[revision(start = 5)]
 	Access(AccessStatement),
+	#[revision(start = 6)]
+	Explain(ExplainStatement),
 }
 
 impl Statement {
@@ -106,6 +108,7 @@ impl Statement {
 		match self {
 			Self::Create(v) => v.timeout.as_ref().map(|v| *v.0),
 			Self::Delete(v) => v.timeout.as_ref().map(|v| *v.0),
+			Self::Explain(v) => v.timeout(),
 			Self::Insert(v) => v.timeout.as_ref().map(|v| *v.0),
 			Self::Relate(v) => v.timeout.as_ref().map(|v| *v.0),
 			Self::Select(v) => v.timeout.as_ref().map(|v| *v.0),
@@ -126,6 +129,7 @@ impl Statement {
 			Self::Create(v) => v.writeable(),
 			Self::Define(_) => true,
 			Self::Delete(v) => v.writeable(),
+			Self::Explain(v) => v.writeable(),
 			Self::Foreach(v) => v.writeable(),
 			Self::Ifelse(v) => v.writeable(),
 			Self::Info(_) => false,
@@ -165,6 +169,7 @@ impl Statement {
 			Self::Create(v) => v.compute(stk, ctx, opt, doc).await,
 			Self::Delete(v) => v.compute(stk, ctx, opt, doc).await,
 			Self::Define(v) => v.compute(stk, ctx, opt, doc).await,
+			Self::Explain(v) => v.compute(stk, ctx, opt, doc).await,
 			Self::Foreach(v) => v.compute(stk, ctx, opt, doc).await,
 			Self::Ifelse(v) => v.compute(stk, ctx, opt, doc).await,
 			Self::Info(v) => v.compute(ctx, opt, doc).await,
@@ -208,6 +213,7 @@ impl Display for Statement {
 			Self::Create(v) => write!(Pretty::from(f), "{v}"),
 			Self::Define(v) => write!(Pretty::from(f), "{v}"),
 			Self::Delete(v) => write!(Pretty::from(f), "{v}"),
+			Self::Explain(v) => write!(Pretty::from(f), "{v}"),
 			Self::Foreach(v) => write!(Pretty::from(f), "{v}"),
 			Self::Insert(v) => write!(Pretty::from(f), "{v}"),
 			Self::Ifelse(v) => write!(Pretty::from(f), "{v}"),
@@ -0,0 +1,71 @@
+use crate::ctx::Context;
+use crate::dbs::Options;
+use crate::doc::CursorDoc;
+use crate::err::Error;
+use crate::sql::statements::{DeleteStatement, SelectStatement, UpdateStatement};
+use crate::sql::{Explain, Fields, Value, With};
+use derive::Store;
+use reblessive::tree::Stk;
+use revision::revisioned;
+use serde::{Deserialize, Serialize};
+use std::fmt::{self, Display, Formatter};
+use std::time::Duration;
+
+#[revisioned(revision = 1)]
+#[derive(Clone, Debug, Eq, PartialEq, PartialOrd, Serialize, Deserialize, Store, Hash)]
+#[cfg_attr(feature = "arbitrary", derive(arbitrary::Arbitrary))]
+#[non_exhaustive]
+pub enum ExplainStatement {
+	Update(UpdateStatement),
+	Delete(DeleteStatement),
+}
+
+impl ExplainStatement {
+	/// Get the statement timeout duration, if any
+	pub(crate) fn timeout(&self) -> Option<Duration> {
+		match self {
+			Self::Update(v) => v.timeout.as_ref().map(|v| *v.0),
+			Self::Delete(v) => v.timeout.as_ref().map(|v| *v.0),
+		}
+	}
+	/// Check if we require a writeable transaction
+	pub(crate) fn writeable(&self) -> bool {
+		false
+	}
+	/// Process this type returning a computed simple Value
+	pub(crate) async fn compute(
+		&self,
+		stk: &mut Stk,
+		ctx: &Context,
+		opt: &Options,
+		doc: Option<&CursorDoc>,
+	) -> Result<Value, Error> {
+		// The plan is that of the records the statement would iterate over
+		let (what, cond, timeout, parallel) = match self {
+			Self::Update(v) => (&v.what, &v.cond, &v.timeout, v.parallel),
+			Self::Delete(v) => (&v.what, &v.cond, &v.timeout, v.parallel),
+		};
+		// Mutations do not use the query planner, so indexes
+		// are disabled to report the same iteration
+		let stm = SelectStatement {
+			expr: Fields::all(),
+			what: what.clone(),
+			with: Some(With::NoIndex),
+			cond: cond.clone(),
+			timeout: timeout.clone(),
+			parallel,
+			explain: Some(Explain(false)),
+			..Default::default()
+		};
+		stm.compute(stk, ctx, opt, doc).await
+	}
+}
+
+impl Display for ExplainStatement {
+	fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+		match self {
+			Self::Update(v) => write!(f, "EXPLAIN {v}"),
+			Self::Delete(v) => write!(f, "EXPLAIN {v}"),
+		}
+	}
+}
@@ -65,8 +65,8 @@ impl KillStatement {
 				txn.del(key).await?;
 			}
 			None => {
-				return Err(Error::KillStatement {
-					value: self.id.to_string(),
+				return Err(Error::LvNotFound {
+					value: lid.to_string(),
 				});
 			}
 		}
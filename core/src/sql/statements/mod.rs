@@ -9,6 +9,7 @@ pub(crate) mod r#continue;
 pub(crate) mod create;
 pub(crate) mod define;
 pub(crate) mod delete;
+pub(crate) mod explain;
 pub(crate) mod foreach;
 pub(crate) mod ifelse;
 pub(crate) mod info;
@@ -38,6 +39,7 @@ pub use self::cancel::CancelStatement;
 pub use self::commit::CommitStatement;
 pub use self::create::CreateStatement;
 pub use self::delete::DeleteStatement;
+pub use self::explain::ExplainStatement;
 pub use self::foreach::ForeachStatement;
 pub use self::ifelse::IfelseStatement;
 pub use self::info::InfoStatement;
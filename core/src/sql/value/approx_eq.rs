@@ -0,0 +1,53 @@
+use crate::sql::value::Value;
+
+impl Value {
+	/// Checks whether two values are equal, allowing numbers to differ by at most `epsilon`.
+	///
+	/// Arrays and objects are compared structurally, with each of their
+	/// entries compared in the same way. Any other values must be equal.
+	pub fn approx_eq(&self, other: &Value, epsilon: f64) -> bool {
+		match (self, other) {
+			(Value::Number(a), Value::Number(b)) => {
+				let (a, b) = (a.to_float(), b.to_float());
+				a == b || (a - b).abs() <= epsilon
+			}
+			(Value::Array(a), Value::Array(b)) => {
+				a.len() == b.len() && a.iter().zip(b.iter()).all(|(a, b)| a.approx_eq(b, epsilon))
+			}
+			(Value::Object(a), Value::Object(b)) => {
+				a.len() == b.len()
+					&& a.iter().all(|(k, a)| b.get(k).is_some_and(|b| a.approx_eq(b, epsilon)))
+			}
+			(a, b) => a == b,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+	use crate::syn::Parse;
+
+	#[test]
+	fn approx_eq_numbers() {
+		let a = Value::from(0.1 + 0.2);
+		let b = Value::from(0.3);
+		assert_ne!(a, b);
+		assert!(a.approx_eq(&b, 1e-9));
+		assert!(!a.approx_eq(&b, 0.0));
+		assert!(Value::from(1).approx_eq(&Value::from(1.0000001), 1e-6));
+		assert!(!Value::from(1).approx_eq(&Value::from(1.1), 1e-6));
+	}
+
+	#[test]
+	fn approx_eq_containers() {
+		let a = Value::parse("{ a: [0.1, 1], b: 'test' }");
+		let b = Value::parse("{ a: [0.1000000001, 1], b: 'test' }");
+		assert!(a.approx_eq(&b, 1e-9));
+		let c = Value::parse("{ a: [0.1000000001, 1], b: 'other' }");
+		assert!(!a.approx_eq(&c, 1e-9));
+		let d = Value::parse("{ a: [0.1000000001], b: 'test' }");
+		assert!(!a.approx_eq(&d, 1e-9));
+	}
+}
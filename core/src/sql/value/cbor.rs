@@ -0,0 +1,58 @@
+use crate::err::Error;
+use crate::rpc::format::cbor::Cbor;
+use crate::sql::value::Value;
+use ciborium::Value as Data;
+
+impl Value {
+	/// Encodes this value as CBOR, using the same tagging as the RPC wire format.
+	pub fn to_cbor(&self) -> Result<Vec<u8>, Error> {
+		let val = Cbor::try_from(self.clone()).map_err(|e| Error::Serialization(e.to_owned()))?;
+		let mut res = Vec::new();
+		ciborium::into_writer(&val.0, &mut res).map_err(|e| Error::Serialization(e.to_string()))?;
+		Ok(res)
+	}
+
+	/// Decodes a value from CBOR, as encoded by [`Value::to_cbor`] or an RPC client.
+	pub fn from_cbor(bytes: &[u8]) -> Result<Value, Error> {
+		let val = ciborium::from_reader::<Data, _>(bytes)
+			.map_err(|e| Error::Serialization(e.to_string()))?;
+		Value::try_from(Cbor(val)).map_err(|e| Error::Serialization(e.to_owned()))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+	use crate::syn::Parse;
+
+	#[test]
+	fn cbor_roundtrip_record() {
+		let val = Value::parse("person:tobie");
+		let res = Value::from_cbor(&val.to_cbor().unwrap()).unwrap();
+		assert_eq!(res, val);
+		assert!(res.is_thing());
+	}
+
+	#[test]
+	fn cbor_roundtrip_datetime() {
+		let val = Value::parse("d'2024-06-01T12:30:45.123456789Z'");
+		let res = Value::from_cbor(&val.to_cbor().unwrap()).unwrap();
+		assert_eq!(res, val);
+		assert!(res.is_datetime());
+	}
+
+	#[test]
+	fn cbor_roundtrip_nested() {
+		let val = Value::parse(
+			"{ id: person:tobie, tags: ['a', 'b'], meta: { uuid: u'0191f946-936f-7223-bef5-aebbc527ad80', age: 18, score: 1.5, extra: NONE, empty: NULL } }",
+		);
+		let res = Value::from_cbor(&val.to_cbor().unwrap()).unwrap();
+		assert_eq!(res, val);
+	}
+
+	#[test]
+	fn cbor_invalid_input() {
+		assert!(Value::from_cbor(&[0xff, 0x00]).is_err());
+	}
+}
@@ -0,0 +1,49 @@
+use crate::sql::value::Value;
+
+impl Value {
+	/// Returns the maximum nesting depth of this value.
+	///
+	/// Any value which is not an array or an object has a depth of 1, and
+	/// each level of array or object nesting adds 1 to the depth.
+	pub fn depth(&self) -> usize {
+		// Walk the value iteratively, so deep values can't overflow the stack
+		let mut max = 0;
+		let mut stack = vec![(self, 1)];
+		while let Some((v, d)) = stack.pop() {
+			max = max.max(d);
+			match v {
+				Value::Array(v) => stack.extend(v.iter().map(|v| (v, d + 1))),
+				Value::Object(v) => stack.extend(v.values().map(|v| (v, d + 1))),
+				_ => {}
+			}
+		}
+		max
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+	use crate::syn::Parse;
+
+	#[test]
+	fn depth_flat() {
+		assert_eq!(Value::from(1).depth(), 1);
+		assert_eq!(Value::None.depth(), 1);
+		assert_eq!(Value::parse("[]").depth(), 1);
+		assert_eq!(Value::parse("[1, 2, 3]").depth(), 2);
+	}
+
+	#[test]
+	fn depth_nested_object() {
+		let val = Value::parse("{ a: 1, b: { c: { d: 'test' } }, e: { f: 2 } }");
+		assert_eq!(val.depth(), 4);
+	}
+
+	#[test]
+	fn depth_array_of_objects() {
+		let val = Value::parse("[{ a: 1 }, { b: [1, 2] }, 3]");
+		assert_eq!(val.depth(), 4);
+	}
+}
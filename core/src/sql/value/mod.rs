@@ -6,12 +6,15 @@ pub(super) mod serde;
 mod value;
 
 mod all;
+mod approx_eq;
+mod cbor;
 mod changed;
 mod clear;
 mod compare;
 mod cut;
 mod decrement;
 mod def;
+mod depth;
 mod del;
 mod diff;
 mod each;
@@ -32,5 +35,6 @@ mod pick;
 mod put;
 mod replace;
 mod rid;
+mod sanitize;
 mod set;
 mod walk;
@@ -0,0 +1,52 @@
+use crate::sql::value::Value;
+use std::mem;
+
+impl Value {
+	/// Applies a transformation to every string in this value, including
+	/// strings nested within arrays and objects. Object keys are unchanged.
+	pub fn sanitize_strings(self, f: impl Fn(&str) -> String) -> Value {
+		self.sanitize_with(&f)
+	}
+
+	fn sanitize_with<F: Fn(&str) -> String>(self, f: &F) -> Value {
+		match self {
+			Value::Strand(v) => Value::from(f(v.as_str())),
+			Value::Array(mut v) => {
+				for v in v.iter_mut() {
+					*v = mem::take(v).sanitize_with(f);
+				}
+				Value::Array(v)
+			}
+			Value::Object(mut v) => {
+				for v in v.values_mut() {
+					*v = mem::take(v).sanitize_with(f);
+				}
+				Value::Object(v)
+			}
+			v => v,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+
+	use super::*;
+	use crate::syn::Parse;
+
+	#[test]
+	fn sanitize_strings_trim() {
+		let val = Value::parse(
+			"{ ' name ': ' Tobie ', tags: ['  a', 'b  ', 1], address: { city: '\tLondon\n' } }",
+		);
+		let res =
+			Value::parse("{ ' name ': 'Tobie', tags: ['a', 'b', 1], address: { city: 'London' } }");
+		assert_eq!(val.sanitize_strings(|s| s.trim().to_owned()), res);
+	}
+
+	#[test]
+	fn sanitize_strings_other_values() {
+		let val = Value::parse("[1, true, NULL, d'2024-01-01T00:00:00Z', person:one]");
+		assert_eq!(val.clone().sanitize_strings(|s| s.to_uppercase()), val);
+	}
+}
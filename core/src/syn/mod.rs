@@ -2,7 +2,7 @@
 
 use crate::{
 	err::Error,
-	sql::{Datetime, Duration, Idiom, Query, Range, Subquery, Thing, Value},
+	sql::{Datetime, Duration, Idiom, Kind, Query, Range, Subquery, Thing, Value},
 };
 
 pub mod common;
@@ -128,6 +128,19 @@ pub fn idiom(input: &str) -> Result<Idiom, Error> {
 		.map_err(Error::InvalidQuery)
 }
 
+/// Parses a SurrealQL [`Kind`] without enclosing `<` `>`
+#[instrument(level = "debug", name = "parser", skip_all, fields(length = input.len()))]
+pub fn kind(input: &str) -> Result<Kind, Error> {
+	debug!("parsing kind, input = {input}");
+	let mut parser = Parser::new(input.as_bytes());
+	let mut stack = Stack::new();
+	stack
+		.enter(|stk| parser.parse_full_kind(stk))
+		.finish()
+		.map_err(|e| e.render_on(input))
+		.map_err(Error::InvalidQuery)
+}
+
 /// Parse a datetime without enclosing delimiters from a string.
 pub fn datetime_raw(input: &str) -> Result<Datetime, Error> {
 	debug!("parsing datetime, input = {input}");
@@ -23,6 +23,21 @@ fn prepare_number_str(str: &str) -> Cow<str> {
 	}
 }
 
+/// Parses an integer with a `0x`, `0b`, or `0o` prefix, returning `None` for any other integer.
+fn parse_radix_integer(str: &str) -> Option<Result<i64, ParseIntError>> {
+	let (sign, str) = match str.strip_prefix('-') {
+		Some(str) => ("-", str),
+		None => ("", str.strip_prefix('+').unwrap_or(str)),
+	};
+	let radix = match str.get(..2)? {
+		"0x" => 16,
+		"0b" => 2,
+		"0o" => 8,
+		_ => return None,
+	};
+	Some(i64::from_str_radix(&format!("{sign}{}", &str[2..]), radix))
+}
+
 /// Generic integer parsing method,
 /// works for all unsigned integers.
 fn parse_integer<I>(parser: &mut Parser<'_>) -> ParseResult<I>
@@ -173,9 +188,11 @@ impl TokenValue for Number {
 				Ok(Number::Float(float))
 			}
 			NumberKind::Integer => {
-				let integer = prepare_number_str(span.strip_suffix('f').unwrap_or(span))
-					.parse()
-					.map_err(|e| ParseError::new(ParseErrorKind::InvalidInteger(e), number.span))?;
+				let integer = match parse_radix_integer(&prepare_number_str(span)) {
+					Some(res) => res,
+					None => prepare_number_str(span.strip_suffix('f').unwrap_or(span)).parse(),
+				}
+				.map_err(|e| ParseError::new(ParseErrorKind::InvalidInteger(e), number.span))?;
 
 				Ok(Number::Int(integer))
 			}
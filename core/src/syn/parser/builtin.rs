@@ -87,6 +87,7 @@ pub(crate) static PATHS: phf::Map<UniCase<&'static str>, PathKind> = phf_map! {
 		UniCase::ascii("array::any") => PathKind::Function,
 		UniCase::ascii("array::append") => PathKind::Function,
 		UniCase::ascii("array::at") => PathKind::Function,
+		UniCase::ascii("array::binary_search") => PathKind::Function,
 		UniCase::ascii("array::boolean_and") => PathKind::Function,
 		UniCase::ascii("array::boolean_not") => PathKind::Function,
 		UniCase::ascii("array::boolean_or") => PathKind::Function,
@@ -95,19 +96,28 @@ pub(crate) static PATHS: phf::Map<UniCase<&'static str>, PathKind> = phf_map! {
 		UniCase::ascii("array::combine") => PathKind::Function,
 		UniCase::ascii("array::complement") => PathKind::Function,
 		UniCase::ascii("array::concat") => PathKind::Function,
+		UniCase::ascii("array::count_where") => PathKind::Function,
 		UniCase::ascii("array::difference") => PathKind::Function,
 		UniCase::ascii("array::distinct") => PathKind::Function,
 		UniCase::ascii("array::fill") => PathKind::Function,
 		UniCase::ascii("array::filter_index") => PathKind::Function,
 		UniCase::ascii("array::find_index") => PathKind::Function,
 		UniCase::ascii("array::first") => PathKind::Function,
+		UniCase::ascii("array::first_n") => PathKind::Function,
+		UniCase::ascii("array::flat_map") => PathKind::Function,
 		UniCase::ascii("array::flatten") => PathKind::Function,
 		UniCase::ascii("array::group") => PathKind::Function,
+		UniCase::ascii("array::group_adjacent") => PathKind::Function,
+		UniCase::ascii("array::index_of") => PathKind::Function,
 		UniCase::ascii("array::insert") => PathKind::Function,
 		UniCase::ascii("array::intersect") => PathKind::Function,
 		UniCase::ascii("array::is_empty") => PathKind::Function,
+		UniCase::ascii("array::is_sorted") => PathKind::Function,
+		UniCase::ascii("array::is_sorted_by") => PathKind::Function,
 		UniCase::ascii("array::join") => PathKind::Function,
 		UniCase::ascii("array::last") => PathKind::Function,
+		UniCase::ascii("array::last_index_of") => PathKind::Function,
+		UniCase::ascii("array::last_n") => PathKind::Function,
 		UniCase::ascii("array::len") => PathKind::Function,
 		UniCase::ascii("array::logical_and") => PathKind::Function,
 		UniCase::ascii("array::logical_or") => PathKind::Function,
@@ -115,28 +125,47 @@ pub(crate) static PATHS: phf::Map<UniCase<&'static str>, PathKind> = phf_map! {
 		UniCase::ascii("array::matches") => PathKind::Function,
 		UniCase::ascii("array::map") => PathKind::Function,
 		UniCase::ascii("array::max") => PathKind::Function,
+		UniCase::ascii("array::max_by") => PathKind::Function,
 		UniCase::ascii("array::min") => PathKind::Function,
+		UniCase::ascii("array::min_by") => PathKind::Function,
+		UniCase::ascii("array::pad") => PathKind::Function,
+		UniCase::ascii("array::pad_start") => PathKind::Function,
 		UniCase::ascii("array::pop") => PathKind::Function,
 		UniCase::ascii("array::prepend") => PathKind::Function,
 		UniCase::ascii("array::push") => PathKind::Function,
+		UniCase::ascii("array::ranked") => PathKind::Function,
+		UniCase::ascii("array::reduce_right") => PathKind::Function,
 		UniCase::ascii("array::remove") => PathKind::Function,
 		UniCase::ascii("array::repeat") => PathKind::Function,
 		UniCase::ascii("array::range") => PathKind::Function,
 		UniCase::ascii("array::reverse") => PathKind::Function,
+		UniCase::ascii("array::running") => PathKind::Function,
 		UniCase::ascii("array::shuffle") => PathKind::Function,
 		UniCase::ascii("array::slice") => PathKind::Function,
 		UniCase::ascii("array::sort") => PathKind::Function,
+		UniCase::ascii("array::splice") => PathKind::Function,
 		UniCase::ascii("array::swap") => PathKind::Function,
+		UniCase::ascii("array::to_object") => PathKind::Function,
 		UniCase::ascii("array::transpose") => PathKind::Function,
 		UniCase::ascii("array::union") => PathKind::Function,
 		UniCase::ascii("array::sort::asc") => PathKind::Function,
 		UniCase::ascii("array::sort::desc") => PathKind::Function,
+		UniCase::ascii("array::weighted_sample") => PathKind::Function,
 		UniCase::ascii("array::windows") => PathKind::Function,
 		//
+		UniCase::ascii("object::diff") => PathKind::Function,
 		UniCase::ascii("object::entries") => PathKind::Function,
+		UniCase::ascii("object::filter") => PathKind::Function,
+		UniCase::ascii("object::flatten") => PathKind::Function,
 		UniCase::ascii("object::from_entries") => PathKind::Function,
+		UniCase::ascii("object::invert") => PathKind::Function,
 		UniCase::ascii("object::keys") => PathKind::Function,
 		UniCase::ascii("object::len") => PathKind::Function,
+		UniCase::ascii("object::merge_all") => PathKind::Function,
+		UniCase::ascii("object::omit") => PathKind::Function,
+		UniCase::ascii("object::pick") => PathKind::Function,
+		UniCase::ascii("object::rename_keys") => PathKind::Function,
+		UniCase::ascii("object::unflatten") => PathKind::Function,
 		UniCase::ascii("object::values") => PathKind::Function,
 		UniCase::ascii("object::matches") => PathKind::Function,
 		//
@@ -145,10 +174,15 @@ pub(crate) static PATHS: phf::Map<UniCase<&'static str>, PathKind> = phf_map! {
 		UniCase::ascii("count") => PathKind::Function,
 		//
 		UniCase::ascii("crypto::blake3") => PathKind::Function,
+		UniCase::ascii("crypto::jwt::decode") => PathKind::Function,
+		UniCase::ascii("crypto::decrypt") => PathKind::Function,
+		UniCase::ascii("crypto::encrypt") => PathKind::Function,
 		UniCase::ascii("crypto::md5") => PathKind::Function,
 		UniCase::ascii("crypto::sha1") => PathKind::Function,
 		UniCase::ascii("crypto::sha256") => PathKind::Function,
 		UniCase::ascii("crypto::sha512") => PathKind::Function,
+		UniCase::ascii("crypto::totp::generate") => PathKind::Function,
+		UniCase::ascii("crypto::totp::verify") => PathKind::Function,
 		//
 		UniCase::ascii("duration::days") => PathKind::Function,
 		UniCase::ascii("duration::hours") => PathKind::Function,
@@ -180,17 +214,26 @@ pub(crate) static PATHS: phf::Map<UniCase<&'static str>, PathKind> = phf_map! {
 		//
 		UniCase::ascii("math::abs") => PathKind::Function,
 		UniCase::ascii("math::acos") => PathKind::Function,
+		UniCase::ascii("math::approx_eq") => PathKind::Function,
 		UniCase::ascii("math::asin") => PathKind::Function,
 		UniCase::ascii("math::acot") => PathKind::Function,
 		UniCase::ascii("math::atan") => PathKind::Function,
+		UniCase::ascii("math::binomial") => PathKind::Function,
 		UniCase::ascii("math::bottom") => PathKind::Function,
 		UniCase::ascii("math::ceil") => PathKind::Function,
 		UniCase::ascii("math::clamp") => PathKind::Function,
+		UniCase::ascii("math::copysign") => PathKind::Function,
 		UniCase::ascii("math::cos") => PathKind::Function,
 		UniCase::ascii("math::cot") => PathKind::Function,
 		UniCase::ascii("math::deg2rad") => PathKind::Function,
+		UniCase::ascii("math::entropy") => PathKind::Function,
+		UniCase::ascii("math::exponential_moving_average") => PathKind::Function,
+		UniCase::ascii("math::factorial") => PathKind::Function,
 		UniCase::ascii("math::fixed") => PathKind::Function,
 		UniCase::ascii("math::floor") => PathKind::Function,
+		UniCase::ascii("math::gini") => PathKind::Function,
+		UniCase::ascii("math::hypot") => PathKind::Function,
+		UniCase::ascii("math::interpolate") => PathKind::Function,
 		UniCase::ascii("math::interquartile") => PathKind::Function,
 		UniCase::ascii("math::lerp") => PathKind::Function,
 		UniCase::ascii("math::lerpangle") => PathKind::Function,
@@ -204,12 +247,17 @@ pub(crate) static PATHS: phf::Map<UniCase<&'static str>, PathKind> = phf_map! {
 		UniCase::ascii("math::midhinge") => PathKind::Function,
 		UniCase::ascii("math::min") => PathKind::Function,
 		UniCase::ascii("math::mode") => PathKind::Function,
+		UniCase::ascii("math::moving_average") => PathKind::Function,
 		UniCase::ascii("math::nearestrank") => PathKind::Function,
 		UniCase::ascii("math::percentile") => PathKind::Function,
 		UniCase::ascii("math::pow") => PathKind::Function,
 		UniCase::ascii("math::product") => PathKind::Function,
+		UniCase::ascii("math::range_scale") => PathKind::Function,
 		UniCase::ascii("math::round") => PathKind::Function,
+		UniCase::ascii("math::quantize") => PathKind::Function,
 		UniCase::ascii("math::rad2deg") => PathKind::Function,
+		UniCase::ascii("math::safe_div") => PathKind::Function,
+		UniCase::ascii("math::safe_mod") => PathKind::Function,
 		UniCase::ascii("math::sign") => PathKind::Function,
 		UniCase::ascii("math::sin") => PathKind::Function,
 		UniCase::ascii("math::spread") => PathKind::Function,
@@ -259,19 +307,37 @@ pub(crate) static PATHS: phf::Map<UniCase<&'static str>, PathKind> = phf_map! {
 		UniCase::ascii("session::rd") => PathKind::Function,
 		UniCase::ascii("session::token") => PathKind::Function,
 		//
+		UniCase::ascii("string::byte_len") => PathKind::Function,
+		UniCase::ascii("string::camelcase") => PathKind::Function,
+		UniCase::ascii("string::center") => PathKind::Function,
+		UniCase::ascii("string::char_len") => PathKind::Function,
 		UniCase::ascii("string::concat") => PathKind::Function,
 		UniCase::ascii("string::contains") => PathKind::Function,
+		UniCase::ascii("string::dedent") => PathKind::Function,
+		UniCase::ascii("string::diff") => PathKind::Function,
 		UniCase::ascii("string::endsWith") => PathKind::Function,
+		UniCase::ascii("string::format_number") => PathKind::Function,
+		UniCase::ascii("string::fuzzy_contains") => PathKind::Function,
+		UniCase::ascii("string::grapheme_len") => PathKind::Function,
+		UniCase::ascii("string::highlight") => PathKind::Function,
+		UniCase::ascii("string::indent") => PathKind::Function,
 		UniCase::ascii("string::join") => PathKind::Function,
+		UniCase::ascii("string::kebabcase") => PathKind::Function,
 		UniCase::ascii("string::len") => PathKind::Function,
+		UniCase::ascii("string::lines") => PathKind::Function,
 		UniCase::ascii("string::lowercase") => PathKind::Function,
+		UniCase::ascii("string::pascalcase") => PathKind::Function,
 		UniCase::ascii("string::repeat") => PathKind::Function,
 		UniCase::ascii("string::replace") => PathKind::Function,
 		UniCase::ascii("string::reverse") => PathKind::Function,
 		UniCase::ascii("string::slice") => PathKind::Function,
 		UniCase::ascii("string::slug") => PathKind::Function,
+		UniCase::ascii("string::snakecase") => PathKind::Function,
 		UniCase::ascii("string::split") => PathKind::Function,
 		UniCase::ascii("string::startsWith") => PathKind::Function,
+		UniCase::ascii("string::titlecase") => PathKind::Function,
+		UniCase::ascii("string::to_ascii") => PathKind::Function,
+		UniCase::ascii("string::transliterate") => PathKind::Function,
 		UniCase::ascii("string::trim") => PathKind::Function,
 		UniCase::ascii("string::uppercase") => PathKind::Function,
 		UniCase::ascii("string::words") => PathKind::Function,
@@ -309,10 +375,17 @@ pub(crate) static PATHS: phf::Map<UniCase<&'static str>, PathKind> = phf_map! {
 		UniCase::ascii("string::similarity::fuzzy") => PathKind::Function,
 		UniCase::ascii("string::similarity::jaro") => PathKind::Function,
 		UniCase::ascii("string::similarity::smithwaterman") => PathKind::Function,
+		UniCase::ascii("string::mask") => PathKind::Function,
 		UniCase::ascii("string::matches") => PathKind::Function,
 		//
+		UniCase::ascii("time::age") => PathKind::Function,
+		UniCase::ascii("time::age_at") => PathKind::Function,
+		UniCase::ascii("time::bucket") => PathKind::Function,
+		UniCase::ascii("time::bucket_key") => PathKind::Function,
 		UniCase::ascii("time::ceil") => PathKind::Function,
 		UniCase::ascii("time::day") => PathKind::Function,
+		UniCase::ascii("time::diff") => PathKind::Function,
+		UniCase::ascii("time::end_of") => PathKind::Function,
 		UniCase::ascii("time::floor") => PathKind::Function,
 		UniCase::ascii("time::format") => PathKind::Function,
 		UniCase::ascii("time::group") => PathKind::Function,
@@ -325,8 +398,10 @@ pub(crate) static PATHS: phf::Map<UniCase<&'static str>, PathKind> = phf_map! {
 		UniCase::ascii("time::micros") => PathKind::Function,
 		UniCase::ascii("time::millis") => PathKind::Function,
 		UniCase::ascii("time::now") => PathKind::Function,
+		UniCase::ascii("time::now_monotonic") => PathKind::Function,
 		UniCase::ascii("time::round") => PathKind::Function,
 		UniCase::ascii("time::second") => PathKind::Function,
+		UniCase::ascii("time::start_of") => PathKind::Function,
 		UniCase::ascii("time::timezone") => PathKind::Function,
 		UniCase::ascii("time::unix") => PathKind::Function,
 		UniCase::ascii("time::wday") => PathKind::Function,
@@ -338,14 +413,23 @@ pub(crate) static PATHS: phf::Map<UniCase<&'static str>, PathKind> = phf_map! {
 		UniCase::ascii("time::from::millis") => PathKind::Function,
 		UniCase::ascii("time::from::secs") => PathKind::Function,
 		UniCase::ascii("time::from::unix") => PathKind::Function,
+		UniCase::ascii("time::timezone::convert") => PathKind::Function,
+		UniCase::ascii("time::timezone::offset") => PathKind::Function,
 		//
+		UniCase::ascii("type::assert") => PathKind::Function,
 		UniCase::ascii("type::bool") => PathKind::Function,
 		UniCase::ascii("type::bytes") => PathKind::Function,
+		UniCase::ascii("type::cast_array") => PathKind::Function,
+		UniCase::ascii("type::coerce") => PathKind::Function,
+		UniCase::ascii("type::convert") => PathKind::Function,
 		UniCase::ascii("type::datetime") => PathKind::Function,
 		UniCase::ascii("type::decimal") => PathKind::Function,
+		UniCase::ascii("type::depth") => PathKind::Function,
 		UniCase::ascii("type::duration") => PathKind::Function,
+		UniCase::ascii("type::enum") => PathKind::Function,
 		UniCase::ascii("type::float") => PathKind::Function,
 		UniCase::ascii("type::int") => PathKind::Function,
+		UniCase::ascii("type::kind_of") => PathKind::Function,
 		UniCase::ascii("type::number") => PathKind::Function,
 		UniCase::ascii("type::point") => PathKind::Function,
 		UniCase::ascii("type::string") => PathKind::Function,
@@ -407,6 +491,7 @@ pub(crate) static PATHS: phf::Map<UniCase<&'static str>, PathKind> = phf_map! {
 		UniCase::ascii("crypto::bcrypt::compare") => PathKind::Function,
 		UniCase::ascii("crypto::bcrypt::generate") => PathKind::Function,
 		UniCase::ascii("crypto::pbkdf2::compare") => PathKind::Function,
+		UniCase::ascii("crypto::pbkdf2::derive") => PathKind::Function,
 		UniCase::ascii("crypto::pbkdf2::generate") => PathKind::Function,
 		UniCase::ascii("crypto::scrypt::compare") => PathKind::Function,
 		UniCase::ascii("crypto::scrypt::generate") => PathKind::Function,
@@ -87,7 +87,13 @@ pub enum ParseErrorKind {
 	ExceededObjectDepthLimit,
 	ExceededQueryDepthLimit,
 	DurationOverflow,
+	/// A digit separator was not placed between two digits.
+	InvalidDigitSeparator,
 	NoWhitespace,
+	/// A statement was used as a value while subqueries were disallowed.
+	DisallowedSubquery,
+	/// Tokens remained after parsing the full expression.
+	QueryRemaining,
 }
 
 /// A parsing error.
@@ -263,6 +269,24 @@ impl ParseError {
 					snippets: vec![snippet],
 				}
 			}
+			ParseErrorKind::DisallowedSubquery => {
+				let text = "Subqueries are not allowed in this expression";
+				let locations = Location::range_of_span(source, at);
+				let snippet = Snippet::from_source_location_range(source, locations, None);
+				RenderedError {
+					text: text.to_string(),
+					snippets: vec![snippet],
+				}
+			}
+			ParseErrorKind::QueryRemaining => {
+				let text = "The expression was not parsed fully, unexpected trailing input";
+				let locations = Location::range_of_span(source, at);
+				let snippet = Snippet::from_source_location_range(source, locations, None);
+				RenderedError {
+					text: text.to_string(),
+					snippets: vec![snippet],
+				}
+			}
 			ParseErrorKind::ExceededObjectDepthLimit => {
 				let text = "Parsing exceeded the depth limit for objects";
 				let locations = Location::range_of_span(source, at);
@@ -319,6 +343,19 @@ impl ParseError {
 					snippets: vec![snippet],
 				}
 			}
+			ParseErrorKind::InvalidDigitSeparator => {
+				let text = "Invalid digit separator";
+				let locations = Location::range_of_span(source, at);
+				let snippet = Snippet::from_source_location_range(
+					source,
+					locations,
+					Some("Underscores must be placed between two digits"),
+				);
+				RenderedError {
+					text: text.to_string(),
+					snippets: vec![snippet],
+				}
+			}
 			ParseErrorKind::InvalidIdent => {
 				let text = "Duration specified exceeds maximum allowed value";
 				let locations = Location::range_of_span(source, at);
@@ -21,6 +21,16 @@ impl Parser<'_> {
 		Ok(kind)
 	}
 
+	/// Parse a kind without enclosing `<` `>`, which must span the entire input.
+	pub async fn parse_full_kind(&mut self, ctx: &mut Stk) -> ParseResult<Kind> {
+		let kind = self.parse_inner_kind(ctx).await?;
+		let peek = self.peek();
+		if peek.kind != TokenKind::Eof {
+			unexpected!(self, peek.kind, "the end of the kind")
+		}
+		Ok(kind)
+	}
+
 	/// Parse an inner kind, a kind without enclosing `<` `>`.
 	pub async fn parse_inner_kind(&mut self, ctx: &mut Stk) -> ParseResult<Kind> {
 		match self.parse_inner_single_kind(ctx).await? {
@@ -118,6 +118,7 @@ pub struct Parser<'a> {
 	flexible_record_id: bool,
 	object_recursion: usize,
 	query_recursion: usize,
+	no_subqueries: bool,
 }
 
 impl<'a> Parser<'a> {
@@ -132,6 +133,7 @@ impl<'a> Parser<'a> {
 			flexible_record_id: true,
 			object_recursion: 100,
 			query_recursion: 20,
+			no_subqueries: false,
 		}
 	}
 
@@ -150,6 +152,14 @@ impl<'a> Parser<'a> {
 		self
 	}
 
+	/// Disallow statements from being used as values within the parsed query.
+	/// When set, subqueries like `(SELECT * FROM foo)` and blocks like `{ CREATE foo }` are
+	/// rejected, which is useful when parsing untrusted expressions.
+	pub fn with_no_subqueries(mut self, value: bool) -> Self {
+		self.no_subqueries = value;
+		self
+	}
+
 	/// Parse strand like the old parser where a strand which looks like a UUID, Record-Id, Or a
 	/// DateTime will be parsed as a date-time.
 	pub fn with_allow_legacy_strand(mut self, value: bool) -> Self {
@@ -188,6 +198,7 @@ impl<'a> Parser<'a> {
 			table_as_field: false,
 			object_recursion: self.object_recursion,
 			query_recursion: self.query_recursion,
+			no_subqueries: self.no_subqueries,
 		}
 	}
 
@@ -378,11 +389,33 @@ impl<'a> Parser<'a> {
 		Ok(sql::Query(statements))
 	}
 
+	/// Parse a full query, collecting every error instead of failing on the first.
+	///
+	/// After an error the parser skips to the end of the failing statement and continues with
+	/// the next one. The statements which parsed successfully are returned with the errors.
+	pub async fn parse_query_recovering(&mut self, ctx: &mut Stk) -> (sql::Query, Vec<ParseError>) {
+		let (statements, errors) = self.parse_stmt_list_recovering(ctx).await;
+		(sql::Query(statements), errors)
+	}
+
 	/// Parse a single statement.
 	pub async fn parse_statement(&mut self, ctx: &mut Stk) -> ParseResult<sql::Statement> {
 		self.parse_stmt(ctx).await
 	}
 
+	/// Parse a single value expression, like `age >= 18 AND active = true`.
+	///
+	/// Unlike [`Parser::parse_statement`] this requires the entire input to be consumed by the
+	/// expression, returning an error if any tokens remain after it.
+	pub async fn parse_expression_only(&mut self, ctx: &mut Stk) -> ParseResult<sql::Value> {
+		let value = ctx.run(|ctx| self.parse_value_field(ctx)).await?;
+		let token = self.peek();
+		if token.kind != TokenKind::Eof {
+			return Err(ParseError::new(ParseErrorKind::QueryRemaining, token.span));
+		}
+		Ok(value)
+	}
+
 	/// Parse a possibly partial statement.
 	///
 	/// This will try to parse a statement if a full statement can be parsed from the buffer parser
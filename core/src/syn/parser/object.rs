@@ -638,6 +638,9 @@ impl Parser<'_> {
 	/// Expects the starting `{` to have already been eaten and its span to be handed to this
 	/// functions as the `start` parameter.
 	pub(super) async fn parse_block(&mut self, ctx: &mut Stk, start: Span) -> ParseResult<Block> {
+		if self.no_subqueries {
+			return Err(ParseError::new(ParseErrorKind::DisallowedSubquery, start));
+		}
 		let mut statements = Vec::new();
 		loop {
 			while self.eat(t!(";")) {}
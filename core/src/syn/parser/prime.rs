@@ -13,7 +13,7 @@ use crate::{
 			mac::{expected, unexpected},
 			ParseError, ParseErrorKind,
 		},
-		token::{t, Span, TokenKind},
+		token::{t, Span, Token, TokenKind},
 	},
 };
 
@@ -434,8 +434,13 @@ impl Parser<'_> {
 	) -> ParseResult<Value> {
 		let (returns, body) = if self.eat(t!("->")) {
 			let returns = Some(ctx.run(|ctx| self.parse_inner_kind(ctx)).await?);
-			let start = expected!(self, t!("{")).span;
-			let body = Value::Block(Box::new(ctx.run(|ctx| self.parse_block(ctx, start)).await?));
+			// The body is either a block, or a single expression
+			let body = if self.eat(t!("{")) {
+				let start = self.last_span();
+				Value::Block(Box::new(ctx.run(|ctx| self.parse_block(ctx, start)).await?))
+			} else {
+				ctx.run(|ctx| self.parse_value(ctx)).await?
+			};
 			(returns, body)
 		} else {
 			let body = ctx.run(|ctx| self.parse_value(ctx)).await?;
@@ -483,6 +488,7 @@ impl Parser<'_> {
 		start: Span,
 	) -> ParseResult<Value> {
 		let peek = self.peek();
+		self.check_subquery_allowed(peek)?;
 		let res = match peek.kind {
 			t!("RETURN") => {
 				self.pop_peek();
@@ -616,6 +622,7 @@ impl Parser<'_> {
 		start: Option<Span>,
 	) -> ParseResult<Subquery> {
 		let peek = self.peek();
+		self.check_subquery_allowed(peek)?;
 		let res = match peek.kind {
 			t!("RETURN") => {
 				self.pop_peek();
@@ -696,6 +703,25 @@ impl Parser<'_> {
 		Ok(res)
 	}
 
+	/// Returns an error if the token starts a statement while subqueries are disallowed.
+	pub(super) fn check_subquery_allowed(&self, token: Token) -> ParseResult<()> {
+		if !self.no_subqueries {
+			return Ok(());
+		}
+		if matches!(
+			token.kind,
+			t!("RETURN")
+				| t!("SELECT") | t!("CREATE")
+				| t!("UPSERT") | t!("UPDATE")
+				| t!("DELETE") | t!("RELATE")
+				| t!("DEFINE") | t!("REMOVE")
+				| t!("REBUILD")
+		) {
+			return Err(ParseError::new(ParseErrorKind::DisallowedSubquery, token.span));
+		}
+		Ok(())
+	}
+
 	fn starts_disallowed_subquery_statement(kind: TokenKind) -> bool {
 		matches!(
 			kind,
@@ -714,8 +740,12 @@ impl Parser<'_> {
 	/// Parses a strand with legacy rules, parsing to a record id, datetime or uuid if the string
 	/// matches.
 	pub async fn reparse_legacy_strand(&mut self, ctx: &mut Stk, text: &str) -> Option<Value> {
-		if let Ok(x) = Parser::new(text.as_bytes()).parse_thing(ctx).await {
-			return Some(Value::Thing(x));
+		// Only a string which is entirely a record id is treated as one
+		let mut parser = Parser::new(text.as_bytes());
+		if let Ok(x) = parser.parse_thing(ctx).await {
+			if parser.peek().kind == TokenKind::Eof {
+				return Some(Value::Thing(x));
+			}
 		}
 		if let Ok(x) = Parser::new(text.as_bytes()).next_token_value() {
 			return Some(Value::Datetime(x));
@@ -12,15 +12,15 @@ use crate::sql::statements::{
 	},
 	KillStatement, LiveStatement, OptionStatement, SetStatement, ThrowStatement,
 };
-use crate::sql::{Fields, Ident, Param};
+use crate::sql::{Explain, Fields, Ident, Param};
 use crate::syn::parser::{ParseError, ParseErrorKind};
 use crate::syn::token::{t, TokenKind};
 use crate::{
 	sql::{
 		statements::{
 			analyze::AnalyzeStatement, BeginStatement, BreakStatement, CancelStatement,
-			CommitStatement, ContinueStatement, ForeachStatement, InfoStatement, OutputStatement,
-			UseStatement,
+			CommitStatement, ContinueStatement, ExplainStatement, ForeachStatement, InfoStatement,
+			OutputStatement, UseStatement,
 		},
 		Expression, Operator, Statement, Statements, Value,
 	},
@@ -56,30 +56,82 @@ impl Parser<'_> {
 				_ => {
 					let stmt = ctx.run(|ctx| self.parse_stmt(ctx)).await?;
 					res.push(stmt);
-					if !self.eat(t!(";")) {
-						if self.eat(t!("eof")) {
-							break;
-						}
+					self.parse_stmt_end()?;
+				}
+			}
+		}
+		Ok(Statements(res))
+	}
 
-						if Self::token_kind_starts_statement(self.peek_kind()) {
-							// user likely forgot a semicolon.
-							return Err(ParseError::new(
-								ParseErrorKind::UnexpectedExplain {
-									found: self.peek_kind(),
-									expected: "the query to end",
-									explain:
-										"maybe forgot a semicolon after the previous statement?",
-								},
-								self.recent_span(),
-							));
+	/// Parses a list of statements, recovering from any errors at the end of the statement in
+	/// which they occurred, and returning every error found alongside the valid statements.
+	pub async fn parse_stmt_list_recovering(
+		&mut self,
+		ctx: &mut Stk,
+	) -> (Statements, Vec<ParseError>) {
+		let mut res = Vec::new();
+		let mut errors = Vec::new();
+		loop {
+			match self.peek_kind() {
+				// consume any possible empty statements.
+				t!(";") => {
+					self.pop_peek();
+					continue;
+				}
+				t!("eof") => break,
+				_ => {
+					let stmt = match ctx.run(|ctx| self.parse_stmt(ctx)).await {
+						Ok(stmt) => self.parse_stmt_end().map(|_| stmt),
+						Err(e) => Err(e),
+					};
+					match stmt {
+						Ok(stmt) => res.push(stmt),
+						Err(e) => {
+							errors.push(e);
+							self.recover_stmt_end();
 						}
-
-						expected!(self, t!("eof"));
 					}
 				}
 			}
 		}
-		Ok(Statements(res))
+		(Statements(res), errors)
+	}
+
+	/// Checks that a statement is followed by either a semicolon or the end of the query.
+	fn parse_stmt_end(&mut self) -> ParseResult<()> {
+		if self.eat(t!(";")) || self.peek_kind() == t!("eof") {
+			return Ok(());
+		}
+		if Self::token_kind_starts_statement(self.peek_kind()) {
+			// user likely forgot a semicolon.
+			return Err(ParseError::new(
+				ParseErrorKind::UnexpectedExplain {
+					found: self.peek_kind(),
+					expected: "the query to end",
+					explain: "maybe forgot a semicolon after the previous statement?",
+				},
+				self.recent_span(),
+			));
+		}
+		expected!(self, t!("eof"));
+		Ok(())
+	}
+
+	/// Skips ahead to the next semicolon which is not nested within a delimiter, or the end of
+	/// the query.
+	fn recover_stmt_end(&mut self) {
+		self.table_as_field = false;
+		let mut depth = 0usize;
+		loop {
+			match self.peek_kind() {
+				t!("eof") => break,
+				t!(";") if depth == 0 => break,
+				t!("{") | t!("(") | t!("[") => depth += 1,
+				t!("}") | t!(")") | t!("]") => depth = depth.saturating_sub(1),
+				_ => {}
+			}
+			self.pop_peek();
+		}
 	}
 
 	fn token_kind_starts_statement(kind: TokenKind) -> bool {
@@ -91,6 +143,7 @@ impl Parser<'_> {
 				| t!("CANCEL") | t!("COMMIT")
 				| t!("CONTINUE") | t!("CREATE")
 				| t!("DEFINE") | t!("DELETE")
+				| t!("EXPLAIN")
 				| t!("FOR") | t!("IF")
 				| t!("INFO") | t!("INSERT")
 				| t!("KILL") | t!("LIVE")
@@ -165,6 +218,10 @@ impl Parser<'_> {
 				self.pop_peek();
 				ctx.run(|ctx| self.parse_delete_stmt(ctx)).await.map(Statement::Delete)
 			}
+			t!("EXPLAIN") => {
+				self.pop_peek();
+				ctx.run(|ctx| self.parse_explain_stmt(ctx)).await
+			}
 			t!("FOR") => {
 				self.pop_peek();
 				ctx.run(|ctx| self.parse_for_stmt(ctx)).await.map(Statement::Foreach)
@@ -249,6 +306,41 @@ impl Parser<'_> {
 		}
 	}
 
+	/// Parses a statement prefixed with EXPLAIN, which returns the plan of the statement.
+	///
+	/// Only SELECT, UPDATE and DELETE statements can be explained, as these are the
+	/// statements which iterate over existing records. An explained UPDATE or DELETE
+	/// only returns the plan of the records it would iterate over, and never modifies data.
+	///
+	/// # Parser State
+	/// Expects `EXPLAIN` to already be consumed.
+	async fn parse_explain_stmt(&mut self, ctx: &mut Stk) -> ParseResult<Statement> {
+		let full = self.eat(t!("FULL"));
+		let token = self.next();
+		match token.kind {
+			t!("SELECT") => {
+				let mut stmt = ctx.run(|ctx| self.parse_select_stmt(ctx)).await?;
+				let full = full || stmt.explain.as_ref().is_some_and(|e| e.0);
+				stmt.explain = Some(Explain(full));
+				Ok(Statement::Select(stmt))
+			}
+			t!("UPDATE") | t!("DELETE") if full => {
+				unexpected!(self, token.kind, "a SELECT statement" => "EXPLAIN FULL executes the statement, so it can only be used with SELECT statements")
+			}
+			t!("UPDATE") => {
+				let stmt = ctx.run(|ctx| self.parse_update_stmt(ctx)).await?;
+				Ok(Statement::Explain(ExplainStatement::Update(stmt)))
+			}
+			t!("DELETE") => {
+				let stmt = ctx.run(|ctx| self.parse_delete_stmt(ctx)).await?;
+				Ok(Statement::Explain(ExplainStatement::Delete(stmt)))
+			}
+			x => {
+				unexpected!(self, x, "a SELECT, UPDATE, or DELETE statement" => "only statements which iterate over existing records can be explained")
+			}
+		}
+	}
+
 	pub(super) async fn parse_entry(&mut self, ctx: &mut Stk) -> ParseResult<Entry> {
 		enter_query_recursion!(this = self => {
 			this.parse_entry_inner(ctx).await
@@ -1,6 +1,6 @@
 use crate::{
 	sql::{self, Id, Statement, Thing, Value},
-	syn::parser::mac::test_parse,
+	syn::parser::{mac::test_parse, ParseErrorKind, Parser},
 };
 
 mod limit;
@@ -67,3 +67,21 @@ fn escaped_params_backtick() {
 	)
 	.unwrap();
 }
+
+#[test]
+fn recover_multiple_errors() {
+	let src = "RETURN ); RETURN 1; RETURN { a: ] }; RETURN 2";
+	let mut parser = Parser::new(src.as_bytes());
+	let mut stack = reblessive::Stack::new();
+	let (query, errors) = stack.enter(|stk| parser.parse_query_recovering(stk)).finish();
+	// The valid statements are still parsed
+	assert_eq!(query.to_string(), "RETURN 1;\nRETURN 2;");
+	// Every error is reported at the token which caused it
+	assert_eq!(errors.len(), 2, "{errors:?}");
+	assert_eq!(errors[0].at.offset as usize, src.find(')').unwrap());
+	assert_eq!(errors[1].at.offset as usize, src.find(']').unwrap());
+	assert!(errors.iter().all(|e| matches!(e.kind, ParseErrorKind::Unexpected { .. })));
+	// Without recovery only the first error is reported
+	let err = test_parse!(parse_query, src).unwrap_err();
+	assert_eq!(err.at, errors[0].at);
+}
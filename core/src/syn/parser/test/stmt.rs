@@ -21,13 +21,13 @@ use crate::{
 			DefineDatabaseStatement, DefineEventStatement, DefineFieldStatement,
 			DefineFunctionStatement, DefineIndexStatement, DefineNamespaceStatement,
 			DefineParamStatement, DefineStatement, DefineTableStatement, DeleteStatement,
-			ForeachStatement, IfelseStatement, InfoStatement, InsertStatement, KillStatement,
-			OptionStatement, OutputStatement, RelateStatement, RemoveAccessStatement,
-			RemoveAnalyzerStatement, RemoveDatabaseStatement, RemoveEventStatement,
-			RemoveFieldStatement, RemoveFunctionStatement, RemoveIndexStatement,
-			RemoveNamespaceStatement, RemoveParamStatement, RemoveStatement, RemoveTableStatement,
-			RemoveUserStatement, SelectStatement, SetStatement, ThrowStatement, UpdateStatement,
-			UpsertStatement, UseStatement,
+			ExplainStatement, ForeachStatement, IfelseStatement, InfoStatement, InsertStatement,
+			KillStatement, OptionStatement, OutputStatement, RelateStatement,
+			RemoveAccessStatement, RemoveAnalyzerStatement, RemoveDatabaseStatement,
+			RemoveEventStatement, RemoveFieldStatement, RemoveFunctionStatement,
+			RemoveIndexStatement, RemoveNamespaceStatement, RemoveParamStatement, RemoveStatement,
+			RemoveTableStatement, RemoveUserStatement, SelectStatement, SetStatement,
+			ThrowStatement, UpdateStatement, UpsertStatement, UseStatement,
 		},
 		tokenizer::Tokenizer,
 		user::UserDuration,
@@ -1643,6 +1643,48 @@ fn parse_delete() {
 	);
 }
 
+#[test]
+fn parse_explain_delete() {
+	let res = test_parse!(parse_statement, "EXPLAIN DELETE FROM foo WHERE a = 1").unwrap();
+	let expected = Statement::Explain(ExplainStatement::Delete(DeleteStatement {
+		what: Values(vec![Value::Table(Table("foo".to_owned()))]),
+		cond: Some(Cond(Value::Expression(Box::new(Expression::Binary {
+			l: Value::Idiom(Idiom(vec![Part::Field(Ident("a".to_string()))])),
+			o: Operator::Equal,
+			r: Value::Number(Number::Int(1)),
+		})))),
+		..Default::default()
+	}));
+	assert_eq!(res, expected);
+	// The explained statement is kept when displayed
+	let sql = res.to_string();
+	assert_eq!(sql, "EXPLAIN DELETE foo WHERE a = 1");
+	let res = test_parse!(parse_statement, &sql).unwrap();
+	assert_eq!(res, expected);
+	// EXPLAIN FULL would execute the statement
+	test_parse!(parse_statement, "EXPLAIN FULL DELETE foo").unwrap_err();
+	// Only statements which iterate over existing records can be explained
+	test_parse!(parse_statement, "EXPLAIN CREATE foo").unwrap_err();
+	// There is no trailing EXPLAIN clause on mutations
+	test_parse!(parse_query, "DELETE foo EXPLAIN").unwrap_err();
+}
+
+#[test]
+fn parse_explain_update() {
+	let sql = "EXPLAIN UPDATE foo SET a = 2 WHERE a = 1 RETURN NONE PARALLEL";
+	let res = test_parse!(parse_statement, sql).unwrap();
+	let Statement::Explain(ExplainStatement::Update(ref stmt)) = res else {
+		panic!("expected an explained UPDATE statement, found {res:?}");
+	};
+	assert!(stmt.data.is_some());
+	assert_eq!(stmt.output, Some(Output::None));
+	assert!(stmt.parallel);
+	// The explained statement is kept when displayed
+	let sql = res.to_string();
+	assert_eq!(sql, "EXPLAIN UPDATE foo SET a = 2 WHERE a = 1 RETURN NONE PARALLEL");
+	assert_eq!(test_parse!(parse_statement, &sql).unwrap(), res);
+}
+
 #[test]
 fn parse_delete_2() {
 	let res = test_parse!(
@@ -6,7 +6,7 @@ use crate::{
 	sql::{
 		Array, Constant, Id, Number, Object, Query, Statement, Statements, Strand, Thing, Value,
 	},
-	syn::parser::{mac::test_parse, Parser},
+	syn::parser::{mac::test_parse, ParseErrorKind, Parser},
 };
 
 #[test]
@@ -178,7 +178,85 @@ fn scientific_number() {
 	assert_eq!(res.to_string(), "0.000097f")
 }
 
+#[test]
+fn radix_integers() {
+	for (source, expected) in
+		[("0xff", 255), ("0b1010", 10), ("0o17", 15), ("-0xff", -255), ("0xFF_FF", 65535)]
+	{
+		let res = test_parse!(parse_value, source).unwrap();
+		assert_eq!(res, Value::Number(Number::Int(expected)), "{source}");
+	}
+}
+
+#[test]
+fn radix_integers_invalid_digits() {
+	for source in ["0xG", "0b102", "0o8", "0x", "0xfoo", "0bar", "0oops"] {
+		let err = test_parse!(parse_value, source).unwrap_err();
+		assert!(matches!(err.kind, ParseErrorKind::InvalidInteger(_)), "{source}");
+	}
+}
+
+#[test]
+fn digit_separators() {
+	let res = test_parse!(parse_value, "1_000_000").unwrap();
+	assert_eq!(res, Value::Number(Number::Int(1000000)));
+	assert_eq!(res.to_string(), "1000000");
+
+	let res = test_parse!(parse_value, "1_234.567_8").unwrap();
+	assert_eq!(res, Value::Number(Number::Float(1234.5678)));
+
+	let res = test_parse!(parse_value, "1_000ms").unwrap();
+	assert_eq!(res, test_parse!(parse_value, "1s").unwrap());
+}
+
+#[test]
+fn digit_separators_invalid() {
+	for source in ["1__0", "1_", "1_.5", "1.5_", "1e1_0_", "1__000ms"] {
+		let err = test_parse!(parse_value, source).unwrap_err();
+		assert!(matches!(err.kind, ParseErrorKind::InvalidDigitSeparator), "{source}");
+	}
+	Parser::new("_1".as_bytes()).next_token_value::<Number>().unwrap_err();
+}
+
 #[test]
 fn empty_string() {
 	test_parse!(parse_value, "").unwrap_err();
 }
+
+#[test]
+fn expression_only() {
+	let res = test_parse!(parse_expression_only, "age >= 18 AND active = true").unwrap();
+	assert_eq!(res.to_string(), "age >= 18 AND active = true");
+	assert!(matches!(res, Value::Expression(_)));
+}
+
+#[test]
+fn expression_only_trailing_tokens() {
+	let err = test_parse!(parse_expression_only, "age >= 18 SELECT").unwrap_err();
+	assert!(matches!(err.kind, ParseErrorKind::QueryRemaining));
+	test_parse!(parse_expression_only, "age >= 18; age < 30").unwrap_err();
+}
+
+#[test]
+fn expression_only_no_subqueries() {
+	let mut stack = Stack::new();
+	for source in ["(SELECT * FROM person)", "count(DELETE person)", "{ CREATE person }"] {
+		let mut parser = Parser::new(source.as_bytes()).with_no_subqueries(true);
+		let err = stack.enter(|stk| parser.parse_expression_only(stk)).finish().expect_err(source);
+		assert!(matches!(err.kind, ParseErrorKind::DisallowedSubquery), "{source}");
+	}
+
+	let mut parser = Parser::new("(age + 1) * 2 > 40".as_bytes()).with_no_subqueries(true);
+	stack.enter(|stk| parser.parse_expression_only(stk)).finish().unwrap();
+}
+
+#[test]
+fn legacy_strand_record_id() {
+	let res = crate::syn::value_legacy_strand(r#""person:tobie""#).unwrap();
+	assert!(matches!(res, Value::Thing(_)));
+	// A string which only starts with a record id is left as a string
+	for source in [r#""person:1..100""#, r#""person:tobie and more""#] {
+		let res = crate::syn::value_legacy_strand(source).unwrap();
+		assert!(matches!(res, Value::Strand(_)), "{source}");
+	}
+}
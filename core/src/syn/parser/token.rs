@@ -240,9 +240,11 @@ impl Parser<'_> {
 				}
 
 				self.pop_peek();
+				self.check_digit_separators(n)?;
 			}
 			TokenKind::Digits => {
 				self.pop_peek();
+				self.check_digit_separators(start)?;
 				debug_assert!(
 					start.is_followed_by(&self.peek_whitespace()),
 					"a whitespace token was eaten where eating it would disturb parsing\n {:?}@{:?} => {:?}@{:?}",
@@ -255,6 +257,23 @@ impl Parser<'_> {
 			_ => return Ok(start),
 		};
 
+		// Check for a hexadecimal, binary, or octal integer, such as `0xff`
+		let last = self.last_span();
+		if self.span_str(last) == "0" {
+			let p = self.peek_whitespace();
+			if p.kind == TokenKind::Identifier && self.span_str(p.span).starts_with(['x', 'b', 'o'])
+			{
+				self.pop_peek();
+				self.lexer.string.take();
+				let token = Token {
+					kind: TokenKind::Number(NumberKind::Integer),
+					span: start.span.covers(p.span),
+				};
+				self.prepend_token(token);
+				return Ok(token);
+			}
+		}
+
 		let mut kind = NumberKind::Integer;
 
 		// Check for mantissa
@@ -265,6 +284,7 @@ impl Parser<'_> {
 				unexpected!(self, next.kind, "digits after the dot");
 			}
 			self.pop_peek();
+			self.check_digit_separators(next)?;
 			kind = NumberKind::Float;
 		}
 
@@ -283,7 +303,8 @@ impl Parser<'_> {
 				TokenKind::Digits => {}
 				x => unexpected!(self, x, "digits after the exponent"),
 			}
-			self.pop_peek();
+			let digits = self.pop_peek();
+			self.check_digit_separators(digits)?;
 			kind = NumberKind::Float;
 		}
 
@@ -344,7 +365,8 @@ impl Parser<'_> {
 
 			self.pop_peek();
 
-			let digits_str = self.span_str(cur.span);
+			self.check_digit_separators(cur)?;
+			let digits_str = self.span_str(cur.span).replace('_', "");
 			let digits_value: u64 = digits_str
 				.parse()
 				.map_err(ParseErrorKind::InvalidInteger)
@@ -444,9 +466,11 @@ impl Parser<'_> {
 					unexpected!(@span, self,digits_token.kind, "a floating point number")
 				}
 				self.pop_peek();
+				self.check_digit_separators(digits_token)?;
 			}
 			TokenKind::Digits => {
 				self.pop_peek();
+				self.check_digit_separators(start)?;
 
 				debug_assert!(
 					start.is_followed_by(&self.peek_whitespace()),
@@ -467,6 +491,7 @@ impl Parser<'_> {
 				unexpected!(self, digits_token.kind, "a floating point number")
 			}
 			self.pop_peek();
+			self.check_digit_separators(digits_token)?;
 		};
 
 		// check for exponent
@@ -483,6 +508,7 @@ impl Parser<'_> {
 				unexpected!(self, digits_token.kind, "a floating point number")
 			}
 			self.pop_peek();
+			self.check_digit_separators(digits_token)?;
 		}
 
 		// check for exponent
@@ -513,6 +539,15 @@ impl Parser<'_> {
 		Ok(token)
 	}
 
+	/// Checks that every underscore in a digits token sits between two digits.
+	fn check_digit_separators(&self, token: Token) -> ParseResult<()> {
+		let digits = self.span_str(token.span);
+		if digits.ends_with('_') || digits.contains("__") {
+			return Err(ParseError::new(ParseErrorKind::InvalidDigitSeparator, token.span));
+		}
+		Ok(())
+	}
+
 	pub fn glue_plain_strand(&mut self) -> ParseResult<Token> {
 		let start = self.peek();
 		match start.kind {
@@ -103,3 +103,52 @@ async fn closures_inline() -> Result<(), Error> {
 	//
 	Ok(())
 }
+
+#[tokio::test]
+async fn closures_typed_return() -> Result<(), Error> {
+	let sql = "
+		LET $block = |$x: int| -> int { $x + 1 };
+		$block(1);
+
+		LET $expr = |$x: int| -> int $x + 1;
+		$expr(1);
+
+		LET $invalid = |$x: int| -> string $x + 1;
+		$invalid(1);
+	";
+	let dbs = new_ds().await?;
+	let ses = Session::owner().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 6);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::None;
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("2");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::None;
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("2");
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::None;
+	assert_eq!(tmp, val);
+	//
+	match res.remove(0).result {
+		Err(Error::FunctionCheck {
+			name,
+			value,
+			check,
+		}) if name == "ANONYMOUS" && value == "2" && check == "string" => (),
+		_ => panic!("Invocation should have failed with error"),
+	}
+	//
+	Ok(())
+}
@@ -42,6 +42,72 @@ async fn delete() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn delete_explain() -> Result<(), Error> {
+	let sql = "
+		CREATE person:one, person:two;
+		EXPLAIN DELETE person WHERE name = 'Tester';
+		EXPLAIN DELETE person:one;
+		SELECT * FROM person;
+	";
+	let dbs = new_ds().await?;
+	let ses = Session::owner().with_ns("test").with_db("test");
+	let res = &mut dbs.execute(sql, &ses, None).await?;
+	assert_eq!(res.len(), 4);
+	//
+	let _ = res.remove(0).result?;
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				detail: {
+					table: 'person'
+				},
+				operation: 'Iterate Table'
+			},
+			{
+				detail: {
+					reason: 'WITH NOINDEX'
+				},
+				operation: 'Fallback'
+			},
+			{
+				detail: {
+					type: 'Memory'
+				},
+				operation: 'Collector'
+			}
+		]",
+	);
+	assert_eq!(tmp, val);
+	//
+	let tmp = res.remove(0).result?;
+	let val = Value::parse(
+		"[
+			{
+				detail: {
+					thing: person:one
+				},
+				operation: 'Iterate Thing'
+			},
+			{
+				detail: {
+					type: 'Memory'
+				},
+				operation: 'Collector'
+			}
+		]",
+	);
+	assert_eq!(tmp, val);
+	// The records were not deleted
+	let tmp = res.remove(0).result?;
+	let val = Value::parse("[{ id: person:one }, { id: person:two }]");
+	assert_eq!(tmp, val);
+	//
+	Ok(())
+}
+
 //
 // Permissions
 //
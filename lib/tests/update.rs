@@ -78,6 +78,35 @@ async fn update_merge_and_content() -> Result<(), Error> {
 	Ok(())
 }
 
+#[tokio::test]
+async fn update_explain() -> Result<(), Error> {
+	let sql = "
+		CREATE person:test SET name = 'Tester';
+		EXPLAIN UPDATE person SET name = 'Changed';
+		SELECT * FROM person;
+	";
+	let mut t = Test::new(sql).await?;
+	t.skip_ok(1)?;
+	t.expect_val(
+		"[
+			{
+				detail: {
+					table: 'person'
+				},
+				operation: 'Iterate Table'
+			},
+			{
+				detail: {
+					type: 'Memory'
+				},
+				operation: 'Collector'
+			}
+		]",
+	)?;
+	t.expect_val("[{ id: person:test, name: 'Tester' }]")?;
+	Ok(())
+}
+
 #[tokio::test]
 async fn update_simple_with_input() -> Result<(), Error> {
 	let sql = "
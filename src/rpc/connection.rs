@@ -3,7 +3,7 @@ use crate::cnf::{
 };
 use crate::rpc::failure::Failure;
 use crate::rpc::format::WsFormat;
-use crate::rpc::response::{failure, IntoRpcResponse};
+use crate::rpc::response::{failure, success, IntoRpcResponse};
 use crate::rpc::CONN_CLOSED_ERR;
 use crate::telemetry;
 use crate::telemetry::metrics::ws::RequestContext;
@@ -23,7 +23,9 @@ use surrealdb::kvs::Datastore;
 use surrealdb::rpc::format::Format;
 use surrealdb::rpc::method::Method;
 use surrealdb::rpc::Data;
+use surrealdb::rpc::PreparedStatements;
 use surrealdb::rpc::RpcContext;
+use surrealdb::rpc::RpcError;
 use surrealdb::sql::Array;
 use surrealdb::sql::Value;
 use tokio::sync::{RwLock, Semaphore};
@@ -45,6 +47,7 @@ pub struct Connection {
 	pub(crate) channels: (Sender<Message>, Receiver<Message>),
 	pub(crate) state: Arc<RpcState>,
 	pub(crate) datastore: Arc<Datastore>,
+	pub(crate) prepared: PreparedStatements,
 	#[cfg(surrealdb_unstable)]
 	pub(crate) gql_schema: SchemaCache<Pessimistic>,
 }
@@ -70,6 +73,7 @@ impl Connection {
 			canceller: CancellationToken::new(),
 			channels: channel::bounded(*WEBSOCKET_MAX_CONCURRENT_REQUESTS),
 			state,
+			prepared: PreparedStatements::default(),
 			#[cfg(surrealdb_unstable)]
 			gql_schema: SchemaCache::new(datastore.clone()),
 			datastore,
@@ -412,6 +416,26 @@ impl RpcContext for Connection {
 		}
 	}
 
+	const STREAM_SUPPORT: bool = true;
+
+	async fn handle_stream(&self, chunk: Value) -> Result<(), RpcError> {
+		// Send the chunk to the client as a message without an id
+		success(None, chunk)
+			.send(Arc::new(TelemetryContext::current()), self.format, &self.channels.0)
+			.await;
+		Ok(())
+	}
+
+	const PREPARE_SUPPORT: bool = true;
+
+	fn prepared_statements(&self) -> &PreparedStatements {
+		&self.prepared
+	}
+
+	fn prepared_statements_mut(&mut self) -> &mut PreparedStatements {
+		&mut self.prepared
+	}
+
 	#[cfg(surrealdb_unstable)]
 	const GQL_SUPPORT: bool = true;
 	#[cfg(surrealdb_unstable)]
@@ -120,6 +120,72 @@ async fn signup() -> Result<(), Box<dyn std::error::Error>> {
 	Ok(())
 }
 
+#[test(tokio::test)]
+async fn signup_detailed() -> Result<(), Box<dyn std::error::Error>> {
+	// Setup database server
+	let (addr, mut server) = common::start_server_with_defaults().await.unwrap();
+	// Connect to WebSocket
+	let mut socket = Socket::connect(&addr, SERVER, FORMAT).await?;
+	// Authenticate the connection
+	socket.send_message_signin(USER, PASS, None, None, None).await?;
+	// Specify a namespace and database
+	socket.send_message_use(Some(NS), Some(DB)).await?;
+	// Define a user record access method
+	socket
+		.send_message_query(
+			r#"
+			DEFINE ACCESS user ON DATABASE TYPE RECORD
+				SIGNUP ( CREATE type::thing('user', $name) SET pass = crypto::argon2::generate($pass) )
+				SIGNIN ( SELECT * FROM type::thing('user', $name) WHERE crypto::argon2::compare(pass, $pass) )
+				DURATION FOR SESSION 24h
+			;"#,
+		)
+		.await?;
+	// Send SIGNUP command asking for the created record
+	let res = socket
+		.send_request(
+			"signup",
+			json!([
+				{
+					"ns": NS,
+					"db": DB,
+					"ac": "user",
+					"name": "alice",
+					"pass": "pass",
+				},
+				{ "detailed": true }
+			]),
+		)
+		.await?;
+	assert!(res["result"].is_object(), "result: {res:?}");
+	// Verify it returns a token
+	assert!(res["result"]["token"].is_string(), "result: {res:?}");
+	let token = res["result"]["token"].as_str().unwrap();
+	assert!(token.starts_with("eyJ0eXAiOiJKV1QiLCJhbGciOiJIUzUxMiJ9"), "result: {res:?}");
+	// Verify it returns the created record
+	assert_eq!(res["result"]["id"], "user:alice", "result: {res:?}");
+	// Send SIGNUP command with invalid options
+	let res = socket
+		.send_request(
+			"signup",
+			json!([
+				{
+					"ns": NS,
+					"db": DB,
+					"ac": "user",
+					"name": "bob",
+					"pass": "pass",
+				},
+				{ "detailed": "yes" }
+			]),
+		)
+		.await?;
+	assert!(res["error"].is_object(), "result: {res:?}");
+	// Test passed
+	server.finish().unwrap();
+	Ok(())
+}
+
 #[test(tokio::test)]
 async fn signin() -> Result<(), Box<dyn std::error::Error>> {
 	// Setup database server
@@ -315,6 +381,132 @@ async fn select() -> Result<(), Box<dyn std::error::Error>> {
 	Ok(())
 }
 
+#[test(tokio::test)]
+async fn select_only() -> Result<(), Box<dyn std::error::Error>> {
+	// Setup database server
+	let (addr, mut server) = common::start_server_with_defaults().await.unwrap();
+	// Connect to WebSocket
+	let mut socket = Socket::connect(&addr, SERVER, FORMAT).await?;
+	// Authenticate the connection
+	socket.send_message_signin(USER, PASS, None, None, None).await?;
+	// Specify a namespace and database
+	socket.send_message_use(Some(NS), Some(DB)).await?;
+	// Create a single test record
+	socket.send_message_query("CREATE tester SET name = 'foo'").await?;
+	// Send SELECT command expecting a single result
+	let res = socket.send_request("select", json!(["tester", { "only": true }])).await?;
+	assert!(res["result"].is_object(), "result: {res:?}");
+	assert_eq!(res["result"]["name"], "foo", "result: {res:?}");
+	// Create another test record
+	socket.send_message_query("CREATE tester SET name = 'bar'").await?;
+	// Send SELECT command which now returns more than one result
+	let res = socket.send_request("select", json!(["tester", { "only": true }])).await?;
+	assert!(res["error"].is_object(), "result: {res:?}");
+	let msg = res["error"]["message"].as_str().unwrap();
+	assert!(msg.contains("Expected a single result output"), "result: {res:?}");
+	// Without the option all results are returned
+	let res = socket.send_request("select", json!(["tester", { "only": false }])).await?;
+	assert_eq!(res["result"].as_array().unwrap().len(), 2, "result: {res:?}");
+	// Test passed
+	server.finish().unwrap();
+	Ok(())
+}
+
+#[test(tokio::test)]
+async fn select_paginated() -> Result<(), Box<dyn std::error::Error>> {
+	// Setup database server
+	let (addr, mut server) = common::start_server_with_defaults().await.unwrap();
+	// Connect to WebSocket
+	let mut socket = Socket::connect(&addr, SERVER, FORMAT).await?;
+	// Authenticate the connection
+	socket.send_message_signin(USER, PASS, None, None, None).await?;
+	// Specify a namespace and database
+	socket.send_message_use(Some(NS), Some(DB)).await?;
+	// Create some test records
+	socket
+		.send_message_query(
+			"FOR $i IN [1, 2, 3, 4, 5] { CREATE type::thing('tester', $i) SET num = $i }",
+		)
+		.await?;
+	// Send SELECT command with a limit
+	let res = socket.send_request("select", json!(["tester", { "limit": 2 }])).await?;
+	let res = res["result"].as_array().unwrap();
+	let nums: Vec<_> = res.iter().map(|v| v["num"].as_i64().unwrap()).collect();
+	assert_eq!(nums, vec![1, 2], "result: {res:?}");
+	// Send SELECT command with a limit and a start
+	let res = socket.send_request("select", json!(["tester", { "limit": 2, "start": 2 }])).await?;
+	let res = res["result"].as_array().unwrap();
+	let nums: Vec<_> = res.iter().map(|v| v["num"].as_i64().unwrap()).collect();
+	assert_eq!(nums, vec![3, 4], "result: {res:?}");
+	// Send SELECT command with a start beyond the last page
+	let res = socket.send_request("select", json!(["tester", { "limit": 2, "start": 4 }])).await?;
+	let res = res["result"].as_array().unwrap();
+	let nums: Vec<_> = res.iter().map(|v| v["num"].as_i64().unwrap()).collect();
+	assert_eq!(nums, vec![5], "result: {res:?}");
+	// Send SELECT command with only a start
+	let res = socket.send_request("select", json!(["tester", { "start": 3 }])).await?;
+	assert_eq!(res["result"].as_array().unwrap().len(), 2, "result: {res:?}");
+	// A single record is not affected by pagination
+	let res = socket.send_request("select", json!(["tester:3", { "limit": 0 }])).await?;
+	assert_eq!(res["result"]["num"], 3, "result: {res:?}");
+	// Send SELECT commands with invalid pagination options
+	for opts in [
+		json!({ "limit": -1 }),
+		json!({ "start": -1 }),
+		json!({ "limit": "10" }),
+		json!({ "start": 1.5 }),
+	] {
+		let res = socket.send_request("select", json!(["tester", opts])).await?;
+		assert!(res["error"].is_object(), "result: {res:?}");
+		assert_eq!(res["error"]["code"], -32602, "result: {res:?}");
+	}
+	// Test passed
+	server.finish().unwrap();
+	Ok(())
+}
+
+#[test(tokio::test)]
+async fn select_range() -> Result<(), Box<dyn std::error::Error>> {
+	// Setup database server
+	let (addr, mut server) = common::start_server_with_defaults().await.unwrap();
+	// Connect to WebSocket
+	let mut socket = Socket::connect(&addr, SERVER, FORMAT).await?;
+	// Authenticate the connection
+	socket.send_message_signin(USER, PASS, None, None, None).await?;
+	// Specify a namespace and database
+	socket.send_message_use(Some(NS), Some(DB)).await?;
+	// Create some test records
+	socket
+		.send_message_query(
+			"FOR $i IN [1, 2, 3, 4, 5] { CREATE type::thing('tester', $i) SET num = $i }",
+		)
+		.await?;
+	// Send SELECT command with an exclusive record range
+	let res = socket.send_request("select", json!(["tester:2..4"])).await?;
+	assert!(res["result"].is_array(), "result: {res:?}");
+	let res = res["result"].as_array().unwrap();
+	let nums: Vec<_> = res.iter().map(|v| v["num"].as_i64().unwrap()).collect();
+	assert_eq!(nums, vec![2, 3], "result: {res:?}");
+	// Send SELECT command with an inclusive record range
+	let res = socket.send_request("select", json!(["tester:2..=4"])).await?;
+	let res = res["result"].as_array().unwrap();
+	let nums: Vec<_> = res.iter().map(|v| v["num"].as_i64().unwrap()).collect();
+	assert_eq!(nums, vec![2, 3, 4], "result: {res:?}");
+	// Send SELECT command with a range containing a single record
+	let res = socket.send_request("select", json!(["tester:5.."])).await?;
+	assert!(res["result"].is_array(), "result: {res:?}");
+	assert_eq!(res["result"].as_array().unwrap().len(), 1, "result: {res:?}");
+	// Send SELECT command with a paginated record range
+	let res =
+		socket.send_request("select", json!(["tester:1..=5", { "limit": 2, "start": 1 }])).await?;
+	let res = res["result"].as_array().unwrap();
+	let nums: Vec<_> = res.iter().map(|v| v["num"].as_i64().unwrap()).collect();
+	assert_eq!(nums, vec![2, 3], "result: {res:?}");
+	// Test passed
+	server.finish().unwrap();
+	Ok(())
+}
+
 #[test(tokio::test)]
 async fn insert() -> Result<(), Box<dyn std::error::Error>> {
 	// Setup database server
@@ -356,6 +548,105 @@ async fn insert() -> Result<(), Box<dyn std::error::Error>> {
 	Ok(())
 }
 
+#[test(tokio::test)]
+async fn insert_return() -> Result<(), Box<dyn std::error::Error>> {
+	// Setup database server
+	let (addr, mut server) = common::start_server_with_defaults().await.unwrap();
+	// Connect to WebSocket
+	let mut socket = Socket::connect(&addr, SERVER, FORMAT).await?;
+	// Authenticate the connection
+	socket.send_message_signin(USER, PASS, None, None, None).await?;
+	// Specify a namespace and database
+	socket.send_message_use(Some(NS), Some(DB)).await?;
+	// Send INSERT command without returning the records
+	let res = socket
+		.send_request("insert", json!(["tester", { "name": "foo" }, { "return": "none" }]))
+		.await?;
+	assert!(res["result"].is_array(), "result: {res:?}");
+	let res = res["result"].as_array().unwrap();
+	assert!(res.is_empty(), "result: {res:?}");
+	// Send INSERT command returning the record diff
+	let res = socket
+		.send_request("insert", json!(["tester", { "name": "bar" }, { "return": "diff" }]))
+		.await?;
+	assert!(res["result"].is_array(), "result: {res:?}");
+	let res = res["result"].as_array().unwrap();
+	assert_eq!(res.len(), 1, "result: {res:?}");
+	assert!(res[0].is_array(), "result: {res:?}");
+	// Unknown return types are rejected
+	let res = socket
+		.send_request("insert", json!(["tester", { "name": "baz" }, { "return": "everything" }]))
+		.await?;
+	assert!(res["error"].is_object(), "result: {res:?}");
+	// Verify the data was inserted and can be queried
+	let res = socket.send_message_query("SELECT VALUE name FROM tester ORDER BY name").await?;
+	assert_eq!(res[0]["result"], json!(["bar", "foo"]), "result: {res:?}");
+	// Test passed
+	server.finish().unwrap();
+	Ok(())
+}
+
+#[test(tokio::test)]
+async fn insert_relation() -> Result<(), Box<dyn std::error::Error>> {
+	// Setup database server
+	let (addr, mut server) = common::start_server_with_defaults().await.unwrap();
+	// Connect to WebSocket
+	let mut socket = Socket::connect(&addr, SERVER, FORMAT).await?;
+	// Authenticate the connection
+	socket.send_message_signin(USER, PASS, None, None, None).await?;
+	// Specify a namespace and database
+	socket.send_message_use(Some(NS), Some(DB)).await?;
+	// Create some test records
+	socket.send_message_query("CREATE person:tobie, person:jaime, person:lena").await?;
+	// Insert a single relation
+	let res = socket
+		.send_request(
+			"insert_relation",
+			json!(["knows", { "in": "person:tobie", "out": "person:jaime", "since": 2020 }]),
+		)
+		.await?;
+	assert!(res["result"].is_array(), "result: {res:?}");
+	let res = res["result"].as_array().unwrap();
+	assert_eq!(res.len(), 1, "result: {res:?}");
+	assert_eq!(res[0]["in"], "person:tobie", "result: {res:?}");
+	assert_eq!(res[0]["out"], "person:jaime", "result: {res:?}");
+	// Insert several relations at once
+	let res = socket
+		.send_request(
+			"insert_relation",
+			json!([
+				"knows",
+				[
+					{ "in": "person:jaime", "out": "person:lena" },
+					{ "in": "person:lena", "out": "person:tobie" },
+				]
+			]),
+		)
+		.await?;
+	assert!(res["result"].is_array(), "result: {res:?}");
+	assert_eq!(res["result"].as_array().unwrap().len(), 2, "result: {res:?}");
+	// The relations are graph edges
+	let res = socket.send_message_query("SELECT VALUE ->knows->person FROM person:tobie").await?;
+	assert_eq!(res[0]["result"], json!([["person:jaime"]]), "result: {res:?}");
+	// Relations without both an in and an out are rejected
+	let res =
+		socket.send_request("insert_relation", json!(["knows", { "in": "person:tobie" }])).await?;
+	assert!(res["error"].is_object(), "result: {res:?}");
+	let res = socket
+		.send_request(
+			"insert_relation",
+			json!(["knows", [{ "in": "person:tobie", "out": "person:lena" }, { "out": "person:lena" }]]),
+		)
+		.await?;
+	assert!(res["error"].is_object(), "result: {res:?}");
+	// Nothing was inserted by the rejected requests
+	let res = socket.send_message_query("SELECT * FROM knows").await?;
+	assert_eq!(res[0]["result"].as_array().unwrap().len(), 3, "result: {res:?}");
+	// Test passed
+	server.finish().unwrap();
+	Ok(())
+}
+
 #[test(tokio::test)]
 async fn create() -> Result<(), Box<dyn std::error::Error>> {
 	// Setup database server
@@ -394,6 +685,106 @@ async fn create() -> Result<(), Box<dyn std::error::Error>> {
 	Ok(())
 }
 
+#[test(tokio::test)]
+async fn create_trim() -> Result<(), Box<dyn std::error::Error>> {
+	// Setup database server
+	let (addr, mut server) = common::start_server_with_defaults().await.unwrap();
+	// Connect to WebSocket
+	let mut socket = Socket::connect(&addr, SERVER, FORMAT).await?;
+	// Authenticate the connection
+	socket.send_message_signin(USER, PASS, None, None, None).await?;
+	// Specify a namespace and database
+	socket.send_message_use(Some(NS), Some(DB)).await?;
+	// Send CREATE command with the trim option
+	let res = socket
+		.send_request(
+			"create",
+			json!([
+				"tester:one",
+				{
+					"name": "  Tobie ",
+					"tags": [" a", "b\n", 1],
+					"address": { "city": "\tLondon " },
+				},
+				{ "trim": true }
+			]),
+		)
+		.await?;
+	assert!(res["result"].is_object(), "result: {res:?}");
+	assert_eq!(res["result"]["name"], "Tobie", "result: {res:?}");
+	assert_eq!(res["result"]["tags"], json!(["a", "b", 1]), "result: {res:?}");
+	assert_eq!(res["result"]["address"]["city"], "London", "result: {res:?}");
+	// Send UPDATE command without the trim option
+	let res =
+		socket.send_request("update", json!(["tester:one", { "name": " Jaime " }, {}])).await?;
+	assert_eq!(res["result"]["name"], " Jaime ", "result: {res:?}");
+	// Unknown options are rejected
+	let res = socket.send_request("update", json!(["tester:one", {}, { "strip": true }])).await?;
+	assert!(res["error"].is_object(), "result: {res:?}");
+	// Test passed
+	server.finish().unwrap();
+	Ok(())
+}
+
+#[test(tokio::test)]
+async fn upsert() -> Result<(), Box<dyn std::error::Error>> {
+	// Setup database server
+	let (addr, mut server) = common::start_server_with_defaults().await.unwrap();
+	// Connect to WebSocket
+	let mut socket = Socket::connect(&addr, SERVER, FORMAT).await?;
+	// Authenticate the connection
+	socket.send_message_signin(USER, PASS, None, None, None).await?;
+	// Specify a namespace and database
+	socket.send_message_use(Some(NS), Some(DB)).await?;
+	// Send UPSERT command for a record which does not exist
+	let res = socket.send_request("upsert", json!(["tester:one", { "name": "foo" }])).await?;
+	assert!(res["result"].is_object(), "result: {res:?}");
+	assert_eq!(res["result"]["name"], "foo", "result: {res:?}");
+	// Send UPSERT command which replaces the existing record
+	let res = socket.send_request("upsert", json!(["tester:one", { "value": "bar" }])).await?;
+	assert_eq!(res["result"]["value"], "bar", "result: {res:?}");
+	assert!(res["result"]["name"].is_null(), "result: {res:?}");
+	// A falsy merge flag keeps the replacing behaviour
+	let res =
+		socket.send_request("upsert", json!(["tester:one", { "name": "foo" }, false])).await?;
+	assert_eq!(res["result"]["name"], "foo", "result: {res:?}");
+	assert!(res["result"]["value"].is_null(), "result: {res:?}");
+	// Test passed
+	server.finish().unwrap();
+	Ok(())
+}
+
+#[test(tokio::test)]
+async fn upsert_merge() -> Result<(), Box<dyn std::error::Error>> {
+	// Setup database server
+	let (addr, mut server) = common::start_server_with_defaults().await.unwrap();
+	// Connect to WebSocket
+	let mut socket = Socket::connect(&addr, SERVER, FORMAT).await?;
+	// Authenticate the connection
+	socket.send_message_signin(USER, PASS, None, None, None).await?;
+	// Specify a namespace and database
+	socket.send_message_use(Some(NS), Some(DB)).await?;
+	// Create a test record
+	socket.send_message_query("CREATE tester:one SET name = 'foo', value = 'bar'").await?;
+	// Send UPSERT command which merges into the existing record
+	let res =
+		socket.send_request("upsert", json!(["tester:one", { "value": "baz" }, true])).await?;
+	assert!(res["result"].is_object(), "result: {res:?}");
+	assert_eq!(res["result"]["name"], "foo", "result: {res:?}");
+	assert_eq!(res["result"]["value"], "baz", "result: {res:?}");
+	// Send UPSERT command which merges into a record which does not exist
+	let res = socket.send_request("upsert", json!(["tester:two", { "name": "qux" }, true])).await?;
+	assert_eq!(res["result"]["name"], "qux", "result: {res:?}");
+	// Verify the data was merged
+	let res = socket.send_message_query("SELECT * FROM tester:one").await?;
+	let res = res[0]["result"].as_array().unwrap();
+	assert_eq!(res[0]["name"], "foo", "result: {res:?}");
+	assert_eq!(res[0]["value"], "baz", "result: {res:?}");
+	// Test passed
+	server.finish().unwrap();
+	Ok(())
+}
+
 #[test(tokio::test)]
 async fn update() -> Result<(), Box<dyn std::error::Error>> {
 	// Setup database server
@@ -478,7 +869,7 @@ async fn merge() -> Result<(), Box<dyn std::error::Error>> {
 }
 
 #[test(tokio::test)]
-async fn patch() -> Result<(), Box<dyn std::error::Error>> {
+async fn merge_many() -> Result<(), Box<dyn std::error::Error>> {
 	// Setup database server
 	let (addr, mut server) = common::start_server_with_defaults().await.unwrap();
 	// Connect to WebSocket
@@ -487,82 +878,678 @@ async fn patch() -> Result<(), Box<dyn std::error::Error>> {
 	socket.send_message_signin(USER, PASS, None, None, None).await?;
 	// Specify a namespace and database
 	socket.send_message_use(Some(NS), Some(DB)).await?;
-	// Create a test record
-	socket.send_message_query("CREATE tester:id SET name = 'foo'").await?;
-	// Send PATCH command
+	// Create the test records
+	socket.send_message_query("CREATE tester:a, tester:b, tester:c SET name = 'foo'").await?;
+	// Send MERGE command
 	let res = socket
 		.send_request(
-			"patch",
+			"merge",
 			json!([
-				"tester:id",
+				"tester",
 				[
-					{
-						"op": "add",
-						"path": "value",
-						"value": "bar"
-					},
-					{
-						"op": "remove",
-						"path": "name",
-					}
+					{ "id": "tester:a", "value": 1 },
+					{ "id": "tester:b", "value": 2 },
+					{ "id": "tester:c", "name": "bar" },
 				]
 			]),
 		)
 		.await?;
-	assert!(res["result"].is_object(), "result: {res:?}");
-	let res = res["result"].as_object().unwrap();
-	assert_eq!(res.get("value"), Some(json!("bar")).as_ref(), "result: {res:?}");
-	// Verify the data was patched
-	let res = socket.send_message_query("SELECT * FROM tester").await?;
-	assert!(res[0]["result"].is_array(), "result: {res:?}");
-	let res = res[0]["result"].as_array().unwrap();
-	assert_eq!(res.len(), 1, "result: {res:?}");
-	assert_eq!(res[0]["name"], json!(null), "result: {res:?}");
-	assert_eq!(res[0]["value"], "bar", "result: {res:?}");
+	assert!(res["result"].is_array(), "result: {res:?}");
+	let res = res["result"].as_array().unwrap();
+	assert_eq!(res.len(), 3, "result: {res:?}");
+	assert_eq!(res[0], json!({ "id": "tester:a", "name": "foo", "value": 1 }), "result: {res:?}");
+	assert_eq!(res[1], json!({ "id": "tester:b", "name": "foo", "value": 2 }), "result: {res:?}");
+	assert_eq!(res[2], json!({ "id": "tester:c", "name": "bar" }), "result: {res:?}");
+	// Verify the data was merged
+	let res = socket.send_message_query("SELECT * FROM tester").await?;
+	let res = res[0]["result"].as_array().unwrap();
+	assert_eq!(res.len(), 3, "result: {res:?}");
+	assert_eq!(res[1]["value"], 2, "result: {res:?}");
+	assert_eq!(res[2]["name"], "bar", "result: {res:?}");
+	// Every entry must specify a record id
+	let res = socket.send_request("merge", json!(["tester", [{ "value": 1 }]])).await?;
+	assert!(res["error"].is_object(), "result: {res:?}");
+	// No records are merged when any of the merges fail
+	socket.send_message_query("DEFINE FIELD value ON tester TYPE option<int>").await?;
+	let res = socket
+		.send_request(
+			"merge",
+			json!(["tester", [{ "id": "tester:a", "value": 10 }, { "id": "tester:b", "value": "x" }]]),
+		)
+		.await?;
+	assert!(res["error"].is_object(), "result: {res:?}");
+	let res = socket.send_message_query("SELECT VALUE value FROM tester:a").await?;
+	assert_eq!(res[0]["result"], json!([1]), "result: {res:?}");
+	// Test passed
+	server.finish().unwrap();
+	Ok(())
+}
+
+#[test(tokio::test)]
+async fn patch() -> Result<(), Box<dyn std::error::Error>> {
+	// Setup database server
+	let (addr, mut server) = common::start_server_with_defaults().await.unwrap();
+	// Connect to WebSocket
+	let mut socket = Socket::connect(&addr, SERVER, FORMAT).await?;
+	// Authenticate the connection
+	socket.send_message_signin(USER, PASS, None, None, None).await?;
+	// Specify a namespace and database
+	socket.send_message_use(Some(NS), Some(DB)).await?;
+	// Create a test record
+	socket.send_message_query("CREATE tester:id SET name = 'foo'").await?;
+	// Send PATCH command
+	let res = socket
+		.send_request(
+			"patch",
+			json!([
+				"tester:id",
+				[
+					{
+						"op": "add",
+						"path": "value",
+						"value": "bar"
+					},
+					{
+						"op": "remove",
+						"path": "name",
+					}
+				]
+			]),
+		)
+		.await?;
+	assert!(res["result"].is_object(), "result: {res:?}");
+	let res = res["result"].as_object().unwrap();
+	assert_eq!(res.get("value"), Some(json!("bar")).as_ref(), "result: {res:?}");
+	// Verify the data was patched
+	let res = socket.send_message_query("SELECT * FROM tester").await?;
+	assert!(res[0]["result"].is_array(), "result: {res:?}");
+	let res = res[0]["result"].as_array().unwrap();
+	assert_eq!(res.len(), 1, "result: {res:?}");
+	assert_eq!(res[0]["name"], json!(null), "result: {res:?}");
+	assert_eq!(res[0]["value"], "bar", "result: {res:?}");
+	// Test passed
+	server.finish().unwrap();
+	Ok(())
+}
+
+#[test(tokio::test)]
+async fn patch_merge_patch() -> Result<(), Box<dyn std::error::Error>> {
+	// Setup database server
+	let (addr, mut server) = common::start_server_with_defaults().await.unwrap();
+	// Connect to WebSocket
+	let mut socket = Socket::connect(&addr, SERVER, FORMAT).await?;
+	// Authenticate the connection
+	socket.send_message_signin(USER, PASS, None, None, None).await?;
+	// Specify a namespace and database
+	socket.send_message_use(Some(NS), Some(DB)).await?;
+	// Create a test record
+	socket
+		.send_message_query("CREATE tester:id SET name = 'foo', info = { age: 20, city: 'London' }")
+		.await?;
+	// Send PATCH command with a merge patch
+	let res = socket
+		.send_request(
+			"patch",
+			json!([
+				"tester:id",
+				{
+					"name": null,
+					"info": {
+						"age": 21,
+						"city": null
+					}
+				},
+				{
+					"merge_patch": true
+				}
+			]),
+		)
+		.await?;
+	assert!(res["result"].is_object(), "result: {res:?}");
+	let res = res["result"].as_object().unwrap();
+	assert_eq!(res.get("info"), Some(json!({ "age": 21 })).as_ref(), "result: {res:?}");
+	// Verify the data was patched
+	let res = socket.send_message_query("SELECT * FROM tester").await?;
+	assert!(res[0]["result"].is_array(), "result: {res:?}");
+	let res = res[0]["result"].as_array().unwrap();
+	assert_eq!(res.len(), 1, "result: {res:?}");
+	let res = res[0].as_object().unwrap();
+	assert!(!res.contains_key("name"), "result: {res:?}");
+	assert_eq!(res["info"], json!({ "age": 21 }), "result: {res:?}");
+	// A merge patch must be an object
+	let res =
+		socket.send_request("patch", json!(["tester:id", [1, 2], { "merge_patch": true }])).await?;
+	assert!(res["error"].is_object(), "result: {res:?}");
+	// Test passed
+	server.finish().unwrap();
+	Ok(())
+}
+
+#[test(tokio::test)]
+async fn delete() -> Result<(), Box<dyn std::error::Error>> {
+	// Setup database server
+	let (addr, mut server) = common::start_server_with_defaults().await.unwrap();
+	// Connect to WebSocket
+	let mut socket = Socket::connect(&addr, SERVER, FORMAT).await?;
+	// Authenticate the connection
+	socket.send_message_signin(USER, PASS, None, None, None).await?;
+	// Specify a namespace and database
+	socket.send_message_use(Some(NS), Some(DB)).await?;
+	// Create a test record
+	socket.send_message_query("CREATE tester:id").await?;
+	// Send DELETE command
+	let res = socket.send_request("delete", json!(["tester"])).await?;
+	assert!(res.is_object(), "result: {res:?}");
+	assert!(res["result"].is_array(), "result: {res:?}");
+	let res = res["result"].as_array().unwrap();
+	assert_eq!(res.len(), 1, "result: {res:?}");
+	assert_eq!(res[0]["id"], "tester:id", "result: {res:?}");
+	// Create a test record
+	socket.send_message_query("CREATE tester:id").await?;
+	// Send DELETE command
+	let res = socket.send_request("delete", json!(["tester:id"])).await?;
+	assert!(res.is_object(), "result: {res:?}");
+	assert!(res["result"].is_object(), "result: {res:?}");
+	let res = res["result"].as_object().unwrap();
+	assert_eq!(res["id"], "tester:id", "result: {res:?}");
+	// Verify the data was merged
+	let res = socket.send_message_query("SELECT * FROM tester").await?;
+	assert!(res[0]["result"].is_array(), "result: {res:?}");
+	let res = res[0]["result"].as_array().unwrap();
+	assert_eq!(res.len(), 0, "result: {res:?}");
+	// Test passed
+	server.finish().unwrap();
+	Ok(())
+}
+
+#[test(tokio::test)]
+async fn delete_range() -> Result<(), Box<dyn std::error::Error>> {
+	// Setup database server
+	let (addr, mut server) = common::start_server_with_defaults().await.unwrap();
+	// Connect to WebSocket
+	let mut socket = Socket::connect(&addr, SERVER, FORMAT).await?;
+	// Authenticate the connection
+	socket.send_message_signin(USER, PASS, None, None, None).await?;
+	// Specify a namespace and database
+	socket.send_message_use(Some(NS), Some(DB)).await?;
+	// Create some test records
+	socket
+		.send_message_query(
+			"FOR $i IN [1, 2, 3, 4, 5] { CREATE type::thing('tester', $i) SET num = $i }",
+		)
+		.await?;
+	// Send DELETE command with a record range
+	let res = socket.send_request("delete", json!(["tester:1..3"])).await?;
+	assert!(res["result"].is_array(), "result: {res:?}");
+	let res = res["result"].as_array().unwrap();
+	let nums: Vec<_> = res.iter().map(|v| v["num"].as_i64().unwrap()).collect();
+	assert_eq!(nums, vec![1, 2], "result: {res:?}");
+	// Verify only the records in the range were deleted
+	let res = socket.send_message_query("SELECT VALUE num FROM tester").await?;
+	assert_eq!(res[0]["result"], json!([3, 4, 5]), "result: {res:?}");
+	// Test passed
+	server.finish().unwrap();
+	Ok(())
+}
+
+#[test(tokio::test)]
+async fn query() -> Result<(), Box<dyn std::error::Error>> {
+	// Setup database server
+	let (addr, mut server) = common::start_server_with_defaults().await.unwrap();
+	// Connect to WebSocket
+	let mut socket = Socket::connect(&addr, SERVER, FORMAT).await?;
+	// Authenticate the connection
+	socket.send_message_signin(USER, PASS, None, None, None).await?;
+	// Specify a namespace and database
+	socket.send_message_use(Some(NS), Some(DB)).await?;
+	// Send QUERY command
+	let res =
+		socket.send_request("query", json!(["CREATE tester; SELECT * FROM tester;",])).await?;
+	assert!(res.is_object(), "result: {res:?}");
+	assert!(res["result"].is_array(), "result: {res:?}");
+	let res = res["result"].as_array().unwrap();
+	assert_eq!(res.len(), 2, "result: {res:?}");
+	// Verify the data was created
+	let res = socket.send_message_query("SELECT * FROM tester").await?;
+	assert!(res[0]["result"].is_array(), "result: {res:?}");
+	let res = res[0]["result"].as_array().unwrap();
+	assert_eq!(res.len(), 1, "result: {res:?}");
+	// Test passed
+	server.finish().unwrap();
+	Ok(())
+}
+
+#[test(tokio::test)]
+async fn query_pagination() -> Result<(), Box<dyn std::error::Error>> {
+	// Setup database server
+	let (addr, mut server) = common::start_server_with_defaults().await.unwrap();
+	// Connect to WebSocket
+	let mut socket = Socket::connect(&addr, SERVER, FORMAT).await?;
+	// Authenticate the connection
+	socket.send_message_signin(USER, PASS, None, None, None).await?;
+	// Specify a namespace and database
+	socket.send_message_use(Some(NS), Some(DB)).await?;
+	// Create some records, some of which share a sort key
+	let sql = "INSERT INTO tester [
+		{ id: 1, bucket: 0, num: 1 },
+		{ id: 2, bucket: 1, num: 2 },
+		{ id: 3, bucket: 1, num: 3 },
+		{ id: 4, bucket: 2, num: 4 },
+		{ id: 5, bucket: 2, num: 5 },
+	]";
+	socket.send_message_query(sql).await?;
+	// Request the first page of results
+	let sql = "SELECT id, bucket, num FROM tester ORDER BY bucket DESC, num";
+	let res = socket.send_request("query", json!([sql, {}, { "limit": 3 }])).await?;
+	assert!(res["result"].is_object(), "result: {res:?}");
+	let rows = json!([
+		{ "id": "tester:4", "bucket": 2, "num": 4 },
+		{ "id": "tester:5", "bucket": 2, "num": 5 },
+		{ "id": "tester:2", "bucket": 1, "num": 2 },
+	]);
+	assert_eq!(res["result"]["rows"], rows, "result: {res:?}");
+	let cursor = res["result"]["next_cursor"].as_str().unwrap().to_owned();
+	// Request the next page of results
+	let res =
+		socket.send_request("query", json!([sql, {}, { "limit": 3, "cursor": cursor }])).await?;
+	let rows = json!([
+		{ "id": "tester:3", "bucket": 1, "num": 3 },
+		{ "id": "tester:1", "bucket": 0, "num": 1 },
+	]);
+	assert_eq!(res["result"]["rows"], rows, "result: {res:?}");
+	assert!(res["result"]["next_cursor"].is_null(), "result: {res:?}");
+	// Rows which share a sort key across a page boundary are neither skipped nor repeated
+	let sql = "SELECT * FROM tester ORDER BY bucket";
+	let mut ids = vec![];
+	let mut cursor = serde_json::Value::Null;
+	loop {
+		let res = socket
+			.send_request("query", json!([sql, {}, { "limit": 2, "cursor": cursor }]))
+			.await?;
+		assert!(res["result"].is_object(), "result: {res:?}");
+		for row in res["result"]["rows"].as_array().unwrap() {
+			ids.push(row["id"].clone());
+		}
+		cursor = res["result"]["next_cursor"].clone();
+		if cursor.is_null() {
+			break;
+		}
+	}
+	assert_eq!(json!(ids), json!(["tester:1", "tester:2", "tester:3", "tester:4", "tester:5"]));
+	// Rows must contain their record id to be paginated
+	let sql = "SELECT bucket FROM tester ORDER BY bucket";
+	let res = socket.send_request("query", json!([sql, {}, { "limit": 2 }])).await?;
+	assert!(res["error"].is_object(), "result: {res:?}");
+	// Sort fields can not be aliased, as the cursor is compared with the stored field
+	let sql = "SELECT id, num AS bucket FROM tester ORDER BY bucket";
+	let res = socket.send_request("query", json!([sql, {}, { "limit": 2 }])).await?;
+	assert!(res["error"].is_object(), "result: {res:?}");
+	// Invalid cursors are rejected
+	let res =
+		socket.send_request("query", json!([sql, {}, { "limit": 3, "cursor": "invalid" }])).await?;
+	assert!(res["error"].is_object(), "result: {res:?}");
+	// Queries without an ORDER BY clause can not be paginated
+	let res =
+		socket.send_request("query", json!(["SELECT * FROM tester", {}, { "limit": 3 }])).await?;
+	assert!(res["error"].is_object(), "result: {res:?}");
+	// Test passed
+	server.finish().unwrap();
+	Ok(())
+}
+
+#[test(tokio::test)]
+async fn query_types() -> Result<(), Box<dyn std::error::Error>> {
+	// Setup database server
+	let (addr, mut server) = common::start_server_with_defaults().await.unwrap();
+	// Connect to WebSocket
+	let mut socket = Socket::connect(&addr, SERVER, FORMAT).await?;
+	// Authenticate the connection
+	socket.send_message_signin(USER, PASS, None, None, None).await?;
+	// Specify a namespace and database
+	socket.send_message_use(Some(NS), Some(DB)).await?;
+	// Without a type the parameter is a string
+	let res =
+		socket.send_request("query", json!(["RETURN type::is::int($age)", { "age": "5" }])).await?;
+	assert_eq!(res["result"][0]["result"], false, "result: {res:?}");
+	// With a type the parameter is converted before execution
+	let res = socket
+		.send_request(
+			"query",
+			json!([
+				"RETURN [type::is::int($age), $age + 1]",
+				{ "age": "5" },
+				{ "types": { "age": "int" } }
+			]),
+		)
+		.await?;
+	assert_eq!(res["result"][0]["result"], json!([true, 6]), "result: {res:?}");
+	// Parameters which can not be converted are rejected
+	let res = socket
+		.send_request(
+			"query",
+			json!(["RETURN $age + 1", { "age": "five" }, { "types": { "age": "int" } }]),
+		)
+		.await?;
+	let msg = res["error"]["message"].as_str().unwrap();
+	assert!(msg.contains("Found 'five' for param $age, but expected a int"), "result: {res:?}");
+	// Types must be valid
+	let res = socket
+		.send_request(
+			"query",
+			json!(["RETURN $age", { "age": "5" }, { "types": { "age": "integer!" } }]),
+		)
+		.await?;
+	assert!(res["error"].is_object(), "result: {res:?}");
+	// Test passed
+	server.finish().unwrap();
+	Ok(())
+}
+
+#[test(tokio::test)]
+async fn compute() -> Result<(), Box<dyn std::error::Error>> {
+	// Setup database server
+	let (addr, mut server) = common::start_server_with_defaults().await.unwrap();
+	// Connect to WebSocket
+	let mut socket = Socket::connect(&addr, SERVER, FORMAT).await?;
+	// Authenticate the connection
+	socket.send_message_signin(USER, PASS, None, None, None).await?;
+	// Specify a namespace and database
+	socket.send_message_use(Some(NS), Some(DB)).await?;
+	// Set some session variables
+	socket.send_request("let", json!(["a", 1])).await?;
+	socket.send_request("let", json!(["b", 2])).await?;
+	// Compute an expression from the session variables
+	let res = socket.send_request("compute", json!(["$a + $b"])).await?;
+	assert_eq!(res["result"], json!(3), "result: {res:?}");
+	// Compute an expression with extra variables
+	let res = socket.send_request("compute", json!(["($a + $b) * $c", { "c": 10 }])).await?;
+	assert_eq!(res["result"], json!(30), "result: {res:?}");
+	// Statements are rejected
+	let res = socket.send_request("compute", json!(["CREATE tester SET name = 'foo'"])).await?;
+	assert!(res["error"].is_object(), "result: {res:?}");
+	let res = socket.send_request("compute", json!(["SELECT * FROM tester"])).await?;
+	assert!(res["error"].is_object(), "result: {res:?}");
+	// Nested subqueries are rejected
+	let res = socket.send_request("compute", json!(["1 + (SELECT * FROM tester)"])).await?;
+	assert!(res["error"].is_object(), "result: {res:?}");
+	let res = socket.send_request("compute", json!(["[1, (CREATE tester)]"])).await?;
+	assert!(res["error"].is_object(), "result: {res:?}");
+	// Verify that nothing was created
+	let res = socket.send_message_query("SELECT * FROM tester").await?;
+	assert_eq!(res[0]["result"], json!([]), "result: {res:?}");
+	// Test passed
+	server.finish().unwrap();
+	Ok(())
+}
+
+#[test(tokio::test)]
+async fn consistency() -> Result<(), Box<dyn std::error::Error>> {
+	// Setup database server
+	let (addr, mut server) = common::start_server_with_defaults().await.unwrap();
+	// Connect to WebSocket
+	let mut socket = Socket::connect(&addr, SERVER, FORMAT).await?;
+	// Authenticate the connection
+	socket.send_message_signin(USER, PASS, None, None, None).await?;
+	// Specify a namespace and database
+	socket.send_message_use(Some(NS), Some(DB)).await?;
+	// Strong consistency is supported by every storage engine
+	let res = socket.send_request("consistency", json!(["strong"])).await?;
+	assert!(res["error"].is_null(), "result: {res:?}");
+	// The test server does not support eventual consistency
+	let res = socket.send_request("consistency", json!(["eventual"])).await?;
+	assert!(res["error"].is_object(), "result: {res:?}");
+	let msg = res["error"]["message"].as_str().unwrap();
+	assert!(msg.contains("consistency level is not supported"), "result: {res:?}");
+	// Unknown consistency levels are rejected
+	let res = socket.send_request("consistency", json!(["causal"])).await?;
+	assert!(res["error"].is_object(), "result: {res:?}");
+	// Queries still run with the previously selected level
+	let res = socket.send_message_query("RETURN 1").await?;
+	assert_eq!(res[0]["result"], json!(1), "result: {res:?}");
+	// Test passed
+	server.finish().unwrap();
+	Ok(())
+}
+
+#[test(tokio::test)]
+async fn prepared_statements() -> Result<(), Box<dyn std::error::Error>> {
+	// Setup database server
+	let (addr, mut server) = common::start_server_with_defaults().await.unwrap();
+	// Connect to WebSocket
+	let mut socket = Socket::connect(&addr, SERVER, FORMAT).await?;
+	// Authenticate the connection
+	socket.send_message_signin(USER, PASS, None, None, None).await?;
+	// Specify a namespace and database
+	socket.send_message_use(Some(NS), Some(DB)).await?;
+	// Send PREPARE command
+	let res = socket
+		.send_request("prepare", json!(["CREATE tester SET name = $name RETURN VALUE name"]))
+		.await?;
+	assert!(res["result"].is_string(), "result: {res:?}");
+	let handle = res["result"].as_str().unwrap();
+	// Execute the prepared statement with the first bindings
+	let res = socket.send_request("execute_prepared", json!([handle, { "name": "foo" }])).await?;
+	assert!(res["result"].is_array(), "result: {res:?}");
+	assert_eq!(res["result"][0]["result"], json!(["foo"]), "result: {res:?}");
+	// Execute the prepared statement with the second bindings
+	let res = socket.send_request("execute_prepared", json!([handle, { "name": "bar" }])).await?;
+	assert!(res["result"].is_array(), "result: {res:?}");
+	assert_eq!(res["result"][0]["result"], json!(["bar"]), "result: {res:?}");
+	// Unknown handles are rejected
+	let res = socket
+		.send_request("execute_prepared", json!(["00000000-0000-0000-0000-000000000000"]))
+		.await?;
+	assert!(res["error"].is_object(), "result: {res:?}");
+	// Verify the data was created
+	let res = socket.send_message_query("SELECT VALUE name FROM tester ORDER BY name").await?;
+	assert_eq!(res[0]["result"], json!(["bar", "foo"]), "result: {res:?}");
+	// Test passed
+	server.finish().unwrap();
+	Ok(())
+}
+
+#[test(tokio::test)]
+async fn version() -> Result<(), Box<dyn std::error::Error>> {
+	// Setup database server
+	let (addr, mut server) = common::start_server_with_defaults().await.unwrap();
+	// Connect to WebSocket
+	let socket = Socket::connect(&addr, SERVER, FORMAT).await?;
+	// Send version command
+	let res = socket.send_request("version", json!([])).await?;
+	assert!(res["result"].is_string(), "result: {res:?}");
+	let res = res["result"].as_str().unwrap();
+	assert!(res.starts_with("surrealdb-"), "result: {res}");
+	// Test passed
+	server.finish().unwrap();
+	Ok(())
+}
+
+#[test(tokio::test)]
+async fn health() -> Result<(), Box<dyn std::error::Error>> {
+	// Setup database server
+	let (addr, mut server) = common::start_server_with_defaults().await.unwrap();
+	// Connect to WebSocket
+	let socket = Socket::connect(&addr, SERVER, FORMAT).await?;
+	// Send health command without selecting a namespace or database
+	let res = socket.send_request("health", json!([])).await?;
+	assert!(res["result"].is_object(), "result: {res:?}");
+	assert_eq!(res["result"]["status"], "ok", "result: {res:?}");
+	assert_eq!(res["result"]["storage"], "memory", "result: {res:?}");
+	// Send health command with unexpected params
+	let res = socket.send_request("health", json!(["extra"])).await?;
+	assert!(res["error"].is_object(), "result: {res:?}");
+	// Test passed
+	server.finish().unwrap();
+	Ok(())
+}
+
+#[test(tokio::test)]
+async fn capabilities() -> Result<(), Box<dyn std::error::Error>> {
+	// Setup database server with a restricted configuration
+	let (addr, mut server) = common::start_server(common::StartServerArguments {
+		args: "--deny-funcs http --allow-net 127.0.0.1".to_owned(),
+		..Default::default()
+	})
+	.await
+	.unwrap();
+	// Connect to WebSocket
+	let mut socket = Socket::connect(&addr, SERVER, FORMAT).await?;
+	// Authenticate the connection
+	socket.send_message_signin(USER, PASS, None, None, None).await?;
+	// Send capabilities command
+	let res = socket.send_request("capabilities", json!([])).await?;
+	assert!(res["result"].is_object(), "result: {res:?}");
+	let res = &res["result"];
+	// Scripting was not enabled
+	assert_eq!(res["scripting"], json!(false), "result: {res:?}");
+	// The http functions were denied
+	assert_eq!(res["functions"]["deny"], json!(["http::*"]), "result: {res:?}");
+	// Only a single network target was allowed
+	assert_eq!(res["network_targets"]["allow"].as_array().unwrap().len(), 1, "result: {res:?}");
+	assert_eq!(res["network_targets"]["deny"], json!(false), "result: {res:?}");
+	// The limits are reported
+	assert!(res["limits"]["max_computation_depth"].is_number(), "result: {res:?}");
+	assert!(res["limits"]["transaction_cache_size"].is_number(), "result: {res:?}");
+	// Parameters are not accepted
+	let res = socket.send_request("capabilities", json!(["test"])).await?;
+	assert!(res["error"].is_object(), "result: {res:?}");
+	// Test passed
+	server.finish().unwrap();
+	Ok(())
+}
+
+#[test(tokio::test)]
+async fn connection_info() -> Result<(), Box<dyn std::error::Error>> {
+	// Setup database server
+	let (addr, mut server) = common::start_server_with_defaults().await.unwrap();
+	// Connect to WebSocket
+	let mut socket = Socket::connect(&addr, SERVER, FORMAT).await?;
+	// Authenticate the connection
+	socket.send_message_signin(USER, PASS, None, None, None).await?;
+	// Specify a namespace and database
+	socket.send_message_use(Some(NS), Some(DB)).await?;
+	// Send connection info command
+	let res = socket.send_request("connection_info", json!([])).await?;
+	assert!(res["result"].is_object(), "result: {res:?}");
+	let res = &res["result"];
+	assert_eq!(res["ns"], NS, "result: {res:?}");
+	assert_eq!(res["db"], DB, "result: {res:?}");
+	assert_eq!(res["authenticated"], json!(true), "result: {res:?}");
+	assert_eq!(res["user"], USER, "result: {res:?}");
+	assert!(res["access"].is_null(), "result: {res:?}");
+	// Send INVALIDATE command
+	socket.send_request("invalidate", json!([])).await?;
+	// The session is no longer authenticated
+	let res = socket.send_request("connection_info", json!([])).await?;
+	assert!(res["result"].is_object(), "result: {res:?}");
+	let res = &res["result"];
+	assert_eq!(res["ns"], NS, "result: {res:?}");
+	assert_eq!(res["db"], DB, "result: {res:?}");
+	assert_eq!(res["authenticated"], json!(false), "result: {res:?}");
+	assert!(res["user"].is_null(), "result: {res:?}");
+	assert!(res["expires_at"].is_null(), "result: {res:?}");
+	// Parameters are not accepted
+	let res = socket.send_request("connection_info", json!(["test"])).await?;
+	assert!(res["error"].is_object(), "result: {res:?}");
+	// Test passed
+	server.finish().unwrap();
+	Ok(())
+}
+
+#[test(tokio::test)]
+async fn export() -> Result<(), Box<dyn std::error::Error>> {
+	// Setup database server
+	let (addr, mut server) = common::start_server_with_defaults().await.unwrap();
+	// Connect to WebSocket
+	let mut socket = Socket::connect(&addr, SERVER, FORMAT).await?;
+	// Authenticate the connection
+	socket.send_message_signin(USER, PASS, None, None, None).await?;
+	// Exporting requires a namespace and database
+	let res = socket.send_request("export", json!([])).await?;
+	assert!(res["error"].is_object(), "result: {res:?}");
+	// Specify a namespace and database
+	socket.send_message_use(Some(NS), Some(DB)).await?;
+	// Create some test data
+	socket
+		.send_message_query(
+			"DEFINE TABLE tester SCHEMALESS; CREATE tester:one SET name = 'one'; CREATE other:one",
+		)
+		.await?;
+	// Export the whole database
+	let res = socket.send_request("export", json!([])).await?;
+	assert!(res["result"].is_string(), "result: {res:?}");
+	let sql = res["result"].as_str().unwrap();
+	assert!(sql.contains("DEFINE TABLE tester"), "result: {sql}");
+	assert!(sql.contains("tester:one"), "result: {sql}");
+	assert!(sql.contains("other:one"), "result: {sql}");
+	// Export only the records of a single table
+	let res = socket
+		.send_request("export", json!([{ "tables": ["tester"], "definitions": false }]))
+		.await?;
+	assert!(res["result"].is_string(), "result: {res:?}");
+	let sql = res["result"].as_str().unwrap();
+	assert!(!sql.contains("DEFINE TABLE"), "result: {sql}");
+	assert!(sql.contains("tester:one"), "result: {sql}");
+	assert!(!sql.contains("other:one"), "result: {sql}");
+	// Unknown options are rejected
+	let res = socket.send_request("export", json!([{ "users": true }])).await?;
+	assert!(res["error"].is_object(), "result: {res:?}");
 	// Test passed
 	server.finish().unwrap();
 	Ok(())
 }
 
 #[test(tokio::test)]
-async fn delete() -> Result<(), Box<dyn std::error::Error>> {
-	// Setup database server
+async fn import() -> Result<(), Box<dyn std::error::Error>> {
+	// Setup database servers
 	let (addr, mut server) = common::start_server_with_defaults().await.unwrap();
-	// Connect to WebSocket
+	let (other_addr, mut other_server) = common::start_server_with_defaults().await.unwrap();
+	// Connect to WebSockets
 	let mut socket = Socket::connect(&addr, SERVER, FORMAT).await?;
-	// Authenticate the connection
+	let mut other = Socket::connect(&other_addr, SERVER, FORMAT).await?;
+	// Authenticate the connections
 	socket.send_message_signin(USER, PASS, None, None, None).await?;
+	other.send_message_signin(USER, PASS, None, None, None).await?;
 	// Specify a namespace and database
 	socket.send_message_use(Some(NS), Some(DB)).await?;
-	// Create a test record
-	socket.send_message_query("CREATE tester:id").await?;
-	// Send DELETE command
-	let res = socket.send_request("delete", json!(["tester"])).await?;
-	assert!(res.is_object(), "result: {res:?}");
+	other.send_message_use(Some(NS), Some(DB)).await?;
+	// Create some test data
+	socket
+		.send_message_query(
+			"DEFINE TABLE tester SCHEMALESS; CREATE tester:one SET name = 'one'; CREATE tester:two SET name = 'two', tags = ['a', 'b']",
+		)
+		.await?;
+	// Export the whole database
+	let res = socket.send_request("export", json!([])).await?;
+	assert!(res["result"].is_string(), "result: {res:?}");
+	let sql = res["result"].as_str().unwrap();
+	// Import the export into the fresh datastore
+	let res = other.send_request("import", json!([sql])).await?;
 	assert!(res["result"].is_array(), "result: {res:?}");
-	let res = res["result"].as_array().unwrap();
-	assert_eq!(res.len(), 1, "result: {res:?}");
-	assert_eq!(res[0]["id"], "tester:id", "result: {res:?}");
-	// Create a test record
-	socket.send_message_query("CREATE tester:id").await?;
-	// Send DELETE command
-	let res = socket.send_request("delete", json!(["tester:id"])).await?;
-	assert!(res.is_object(), "result: {res:?}");
-	assert!(res["result"].is_object(), "result: {res:?}");
-	let res = res["result"].as_object().unwrap();
-	assert_eq!(res["id"], "tester:id", "result: {res:?}");
-	// Verify the data was merged
-	let res = socket.send_message_query("SELECT * FROM tester").await?;
-	assert!(res[0]["result"].is_array(), "result: {res:?}");
-	let res = res[0]["result"].as_array().unwrap();
-	assert_eq!(res.len(), 0, "result: {res:?}");
+	let results = res["result"].as_array().unwrap();
+	assert!(!results.is_empty(), "result: {res:?}");
+	assert!(results.iter().all(|r| r["status"] == "OK"), "result: {res:?}");
+	// Both datastores contain the same records
+	let expected = socket.send_message_query("SELECT * FROM tester ORDER BY id").await?;
+	let imported = other.send_message_query("SELECT * FROM tester ORDER BY id").await?;
+	assert_eq!(expected[0]["result"].as_array().unwrap().len(), 2, "result: {expected:?}");
+	assert_eq!(imported[0]["result"], expected[0]["result"]);
+	// Failing statements are reported individually
+	let res = other.send_request("import", json!(["CREATE tester:three; THROW 'fail'"])).await?;
+	assert!(res["result"].is_array(), "result: {res:?}");
+	assert_eq!(res["result"][0]["status"], "OK", "result: {res:?}");
+	assert_eq!(res["result"][1]["status"], "ERR", "result: {res:?}");
+	// The import must be a string of SurrealQL
+	let res = other.send_request("import", json!([1])).await?;
+	assert!(res["error"].is_object(), "result: {res:?}");
 	// Test passed
 	server.finish().unwrap();
+	other_server.finish().unwrap();
 	Ok(())
 }
 
 #[test(tokio::test)]
-async fn query() -> Result<(), Box<dyn std::error::Error>> {
+async fn batch() -> Result<(), Box<dyn std::error::Error>> {
 	// Setup database server
 	let (addr, mut server) = common::start_server_with_defaults().await.unwrap();
 	// Connect to WebSocket
@@ -571,34 +1558,90 @@ async fn query() -> Result<(), Box<dyn std::error::Error>> {
 	socket.send_message_signin(USER, PASS, None, None, None).await?;
 	// Specify a namespace and database
 	socket.send_message_use(Some(NS), Some(DB)).await?;
-	// Send QUERY command
-	let res =
-		socket.send_request("query", json!(["CREATE tester; SELECT * FROM tester;",])).await?;
-	assert!(res.is_object(), "result: {res:?}");
+	// Run a create and an update which both succeed
+	let res = socket
+		.send_request(
+			"batch",
+			json!([[
+				{ "method": "create", "params": ["tester:one", { "name": "one" }] },
+				{ "method": "update", "params": ["tester:one", { "name": "two" }] },
+			]]),
+		)
+		.await?;
 	assert!(res["result"].is_array(), "result: {res:?}");
-	let res = res["result"].as_array().unwrap();
-	assert_eq!(res.len(), 2, "result: {res:?}");
-	// Verify the data was created
-	let res = socket.send_message_query("SELECT * FROM tester").await?;
-	assert!(res[0]["result"].is_array(), "result: {res:?}");
-	let res = res[0]["result"].as_array().unwrap();
-	assert_eq!(res.len(), 1, "result: {res:?}");
+	assert_eq!(res["result"][0]["name"], "one", "result: {res:?}");
+	assert_eq!(res["result"][1]["name"], "two", "result: {res:?}");
+	// Run a create followed by an operation which fails
+	let res = socket
+		.send_request(
+			"batch",
+			json!([[
+				{ "method": "create", "params": ["tester:two", { "name": "two" }] },
+				{ "method": "create", "params": ["tester:one", { "name": "one" }] },
+			]]),
+		)
+		.await?;
+	assert!(res["error"].is_object(), "result: {res:?}");
+	let msg = res["error"]["message"].as_str().unwrap();
+	assert!(msg.contains("The batch operation at index 1 failed"), "result: {res:?}");
+	// The first create was rolled back
+	let res = socket.send_message_query("SELECT * FROM tester ORDER BY id").await?;
+	assert_eq!(res[0]["result"], json!([{ "id": "tester:one", "name": "two" }]), "result: {res:?}");
+	// Unknown methods are rejected
+	let res = socket
+		.send_request("batch", json!([[{ "method": "query", "params": ["INFO FOR DB"] }]]))
+		.await?;
+	assert!(res["error"].is_object(), "result: {res:?}");
+	// A merge must be given the data to merge
+	let res = socket
+		.send_request("batch", json!([[{ "method": "merge", "params": ["tester:one"] }]]))
+		.await?;
+	assert!(res["error"].is_object(), "result: {res:?}");
+	// Session variables are not affected by the operations
+	socket.send_request("let", json!(["data0", "unchanged"])).await?;
+	let res = socket
+		.send_request(
+			"batch",
+			json!([[{ "method": "merge", "params": ["tester:one", { "tag": "merged" }] }]]),
+		)
+		.await?;
+	assert_eq!(res["result"][0]["name"], "two", "result: {res:?}");
+	assert_eq!(res["result"][0]["tag"], "merged", "result: {res:?}");
+	let res = socket.send_message_query("RETURN $data0").await?;
+	assert_eq!(res[0]["result"], "unchanged", "result: {res:?}");
 	// Test passed
 	server.finish().unwrap();
 	Ok(())
 }
 
+#[cfg(surrealdb_unstable)]
 #[test(tokio::test)]
-async fn version() -> Result<(), Box<dyn std::error::Error>> {
+async fn graphql_cbor() -> Result<(), Box<dyn std::error::Error>> {
 	// Setup database server
-	let (addr, mut server) = common::start_server_with_defaults().await.unwrap();
+	let (addr, mut server) = common::start_server_with_graphql().await.unwrap();
 	// Connect to WebSocket
-	let socket = Socket::connect(&addr, SERVER, FORMAT).await?;
-	// Send version command
-	let res = socket.send_request("version", json!([])).await?;
+	let mut socket = Socket::connect(&addr, SERVER, FORMAT).await?;
+	// Authenticate the connection
+	socket.send_message_signin(USER, PASS, None, None, None).await?;
+	// Specify a namespace and database
+	socket.send_message_use(Some(NS), Some(DB)).await?;
+	// Create some test data
+	socket
+		.send_message_query(
+			"DEFINE TABLE tester SCHEMAFULL; DEFINE FIELD name ON tester TYPE string; CREATE tester:one SET name = 'one'",
+		)
+		.await?;
+	let query = json!({ "query": "{ tester { name } }" });
+	// Run the query with a JSON response
+	let res = socket.send_request("graphql", json!([query, { "format": "json" }])).await?;
 	assert!(res["result"].is_string(), "result: {res:?}");
-	let res = res["result"].as_str().unwrap();
-	assert!(res.starts_with("surrealdb-"), "result: {res}");
+	let json: serde_json::Value = serde_json::from_str(res["result"].as_str().unwrap())?;
+	assert_eq!(json["data"]["tester"], json!([{ "name": "one" }]), "result: {json:?}");
+	// Run the same query with a CBOR response
+	let res = socket.send_request("graphql", json!([query, { "format": "cbor" }])).await?;
+	let bytes: Vec<u8> = serde_json::from_value(res["result"].clone())?;
+	let cbor: serde_json::Value = ciborium::from_reader(bytes.as_slice())?;
+	assert_eq!(cbor, json);
 	// Test passed
 	server.finish().unwrap();
 	Ok(())
@@ -710,6 +1753,122 @@ async fn live() -> Result<(), Box<dyn std::error::Error>> {
 	Ok(())
 }
 
+#[test(tokio::test)]
+async fn live_actions() -> Result<(), Box<dyn std::error::Error>> {
+	// Setup database server
+	let (addr, mut server) = common::start_server_with_defaults().await.unwrap();
+	// Connect to WebSocket
+	let mut socket = Socket::connect(&addr, SERVER, FORMAT).await?;
+	// Authenticate the connection
+	socket.send_message_signin(USER, PASS, None, None, None).await?;
+	// Specify a namespace and database
+	socket.send_message_use(Some(NS), Some(DB)).await?;
+	// Send LIVE command only for CREATE actions
+	let res =
+		socket.send_request("live", json!(["tester", false, { "actions": ["CREATE"] }])).await?;
+	assert!(res["result"].is_string(), "result: {res:?}");
+	let live = res["result"].as_str().unwrap();
+	// Unknown actions are rejected
+	let res =
+		socket.send_request("live", json!(["tester", false, { "actions": ["SELECT"] }])).await?;
+	assert!(res["error"].is_object(), "result: {res:?}");
+	// Create a new test record
+	socket.send_message_query("CREATE tester:id SET name = 'foo'").await?;
+	// The CREATE notification is received
+	let msgs = socket.receive_all_other_messages(1, Duration::from_secs(1)).await?;
+	assert!(common::is_notification_from_lq(&msgs[0], live), "result: {msgs:?}");
+	assert_eq!(msgs[0]["result"]["action"], "CREATE", "result: {msgs:?}");
+	// Update the test record
+	socket.send_message_query("UPDATE tester:id SET name = 'bar'").await?;
+	// No UPDATE notification is received
+	let res = socket.receive_all_other_messages(1, Duration::from_millis(500)).await;
+	assert!(res.is_err(), "result: {res:?}");
+	// Delete the test record, and create another
+	socket.send_message_query("DELETE tester:id; CREATE tester:other").await?;
+	// Only the CREATE notification is received
+	let msgs = socket.receive_all_other_messages(1, Duration::from_secs(1)).await?;
+	assert_eq!(msgs[0]["result"]["action"], "CREATE", "result: {msgs:?}");
+	assert_eq!(msgs[0]["result"]["result"]["id"], "tester:other", "result: {msgs:?}");
+	// Test passed
+	server.finish().unwrap();
+	Ok(())
+}
+
+#[test(tokio::test)]
+async fn live_filter() -> Result<(), Box<dyn std::error::Error>> {
+	// Setup database server
+	let (addr, mut server) = common::start_server_with_defaults().await.unwrap();
+	// Connect to WebSocket
+	let mut socket = Socket::connect(&addr, SERVER, FORMAT).await?;
+	// Authenticate the connection
+	socket.send_message_signin(USER, PASS, None, None, None).await?;
+	// Specify a namespace and database
+	socket.send_message_use(Some(NS), Some(DB)).await?;
+	// Send LIVE command only for matching records
+	let res =
+		socket.send_request("live", json!(["tester", false, { "filter": "value > 10" }])).await?;
+	assert!(res["result"].is_string(), "result: {res:?}");
+	let live = res["result"].as_str().unwrap();
+	// Malformed conditions are rejected
+	for filter in [
+		json!("value >"),
+		json!("true; DELETE tester"),
+		json!("(DELETE tester)"),
+		json!("value > 10 AND (SELECT * FROM secret)"),
+		json!(10),
+	] {
+		let res =
+			socket.send_request("live", json!(["tester", false, { "filter": filter }])).await?;
+		assert_eq!(res["error"]["code"], -32602, "result: {res:?}");
+	}
+	// Create a record which does not match
+	socket.send_message_query("CREATE tester:small SET value = 5").await?;
+	// No notification is received
+	let res = socket.receive_all_other_messages(1, Duration::from_millis(500)).await;
+	assert!(res.is_err(), "result: {res:?}");
+	// Create a record which matches
+	socket.send_message_query("CREATE tester:large SET value = 50").await?;
+	// The notification is received
+	let msgs = socket.receive_all_other_messages(1, Duration::from_secs(1)).await?;
+	assert!(common::is_notification_from_lq(&msgs[0], live), "result: {msgs:?}");
+	assert_eq!(msgs[0]["result"]["result"]["id"], "tester:large", "result: {msgs:?}");
+	// Test passed
+	server.finish().unwrap();
+	Ok(())
+}
+
+#[test(tokio::test)]
+async fn live_filter_actions() -> Result<(), Box<dyn std::error::Error>> {
+	// Setup database server
+	let (addr, mut server) = common::start_server_with_defaults().await.unwrap();
+	// Connect to WebSocket
+	let mut socket = Socket::connect(&addr, SERVER, FORMAT).await?;
+	// Authenticate the connection
+	socket.send_message_signin(USER, PASS, None, None, None).await?;
+	// Specify a namespace and database
+	socket.send_message_use(Some(NS), Some(DB)).await?;
+	// Send LIVE command only for matching records which are updated
+	let opts = json!({ "actions": ["UPDATE"], "filter": "value > 10" });
+	let res = socket.send_request("live", json!(["tester", false, opts])).await?;
+	assert!(res["result"].is_string(), "result: {res:?}");
+	let live = res["result"].as_str().unwrap();
+	// Create and update the test records
+	socket
+		.send_message_query("CREATE tester:one SET value = 50; CREATE tester:two SET value = 5")
+		.await?;
+	socket.send_message_query("UPDATE tester SET value += 1").await?;
+	// Only the UPDATE notification for the matching record is received
+	let msgs = socket.receive_all_other_messages(1, Duration::from_secs(1)).await?;
+	assert!(common::is_notification_from_lq(&msgs[0], live), "result: {msgs:?}");
+	assert_eq!(msgs[0]["result"]["action"], "UPDATE", "result: {msgs:?}");
+	assert_eq!(msgs[0]["result"]["result"]["id"], "tester:one", "result: {msgs:?}");
+	let res = socket.receive_all_other_messages(1, Duration::from_millis(500)).await;
+	assert!(res.is_err(), "result: {res:?}");
+	// Test passed
+	server.finish().unwrap();
+	Ok(())
+}
+
 #[test(tokio::test)]
 async fn kill() -> Result<(), Box<dyn std::error::Error>> {
 	// Setup database server
@@ -813,6 +1972,43 @@ async fn kill() -> Result<(), Box<dyn std::error::Error>> {
 	Ok(())
 }
 
+#[test(tokio::test)]
+async fn kill_multiple() -> Result<(), Box<dyn std::error::Error>> {
+	// Setup database server
+	let (addr, mut server) = common::start_server_with_defaults().await.unwrap();
+	// Connect to WebSocket
+	let mut socket = Socket::connect(&addr, SERVER, FORMAT).await?;
+	// Authenticate the connection
+	socket.send_message_signin(USER, PASS, None, None, None).await?;
+	// Specify a namespace and database
+	socket.send_message_use(Some(NS), Some(DB)).await?;
+	// Start two live queries
+	let res = socket.send_request("live", json!(["tester"])).await?;
+	assert!(res["result"].is_string(), "result: {res:?}");
+	let live1 = res["result"].as_str().unwrap().to_owned();
+	let res = socket.send_request("live", json!(["tester"])).await?;
+	assert!(res["result"].is_string(), "result: {res:?}");
+	let live2 = res["result"].as_str().unwrap().to_owned();
+	// Kill the second live query ahead of the batch
+	let res = socket.send_request("kill", json!([live2])).await?;
+	assert!(res["result"].is_null(), "result: {res:?}");
+	// Kill both live queries at once
+	let res = socket.send_request("kill", json!([[live1, live2]])).await?;
+	assert!(res.is_object(), "result: {res:?}");
+	assert!(res["error"].is_null(), "result: {res:?}");
+	let res = res["result"].as_array().unwrap();
+	assert_eq!(res.len(), 2, "result: {res:?}");
+	assert_eq!(res[0]["id"], live1, "result: {res:?}");
+	assert_eq!(res[0]["status"], "OK", "result: {res:?}");
+	assert_eq!(res[1]["id"], live2, "result: {res:?}");
+	assert_eq!(res[1]["status"], "ERR", "result: {res:?}");
+	let msg = res[1]["result"].as_str().unwrap();
+	assert!(msg.contains(&format!("The live query '{live2}' does not exist")), "result: {res:?}");
+	// Test passed
+	server.finish().unwrap();
+	Ok(())
+}
+
 #[test(tokio::test)]
 async fn live_second_connection() -> Result<(), Box<dyn std::error::Error>> {
 	// Setup database server
@@ -1711,6 +2907,157 @@ async fn run_functions() {
 	server.finish().unwrap();
 }
 
+#[test(tokio::test)]
+async fn run_stream() {
+	// Setup database server
+	let (addr, mut server) = common::start_server_with_functions().await.unwrap();
+	// Connect to WebSocket
+	let mut socket = Socket::connect(&addr, SERVER, FORMAT).await.unwrap();
+	// Authenticate the connection
+	socket.send_message_signin(USER, PASS, None, None, None).await.unwrap();
+	// Specify a namespace and database
+	socket.send_message_use(Some(NS), Some(DB)).await.unwrap();
+	// Define a function which yields a large result
+	socket
+		.send_message_query("DEFINE FUNCTION fn::items() { RETURN array::range(1, 1000); }")
+		.await
+		.unwrap();
+	// Run the function with streaming enabled
+	let res = socket
+		.send_request("run", json!(["fn::items", null, null, { "stream": true }]))
+		.await
+		.unwrap();
+	assert!(res.is_object(), "result: {res:?}");
+	let res = res["result"].as_object().unwrap();
+	assert_eq!(res["chunks"], json!(10), "result: {res:?}");
+	assert_eq!(res["count"], json!(1000), "result: {res:?}");
+	// Collect the streamed chunks
+	let msgs = socket.receive_all_other_messages(10, Duration::from_secs(1)).await.unwrap();
+	assert!(msgs.iter().all(|v| v["error"].is_null()), "Unexpected error received: {msgs:?}");
+	let mut items = vec![];
+	for (i, msg) in msgs.iter().enumerate() {
+		let chunk = msg["result"].as_object().unwrap();
+		assert_eq!(chunk["stream"], res["stream"], "chunk: {chunk:?}");
+		assert_eq!(chunk["index"], json!(i), "chunk: {chunk:?}");
+		items.extend(chunk["result"].as_array().unwrap().iter().cloned());
+	}
+	assert_eq!(items, (1..=1000).map(|v| json!(v)).collect::<Vec<_>>());
+	// Run the function with streaming disabled
+	let res = socket
+		.send_request("run", json!(["fn::items", null, null, { "stream": false }]))
+		.await
+		.unwrap();
+	assert_eq!(res["result"].as_array().unwrap().len(), 1000, "result: {res:?}");
+	// Unknown options are rejected
+	let res = socket
+		.send_request("run", json!(["fn::items", null, null, { "invalid": true }]))
+		.await
+		.unwrap();
+	assert!(res["error"].is_object(), "result: {res:?}");
+	// Test passed
+	server.finish().unwrap();
+}
+
+#[test(tokio::test)]
+async fn run_describe() {
+	// Setup database server
+	let (addr, mut server) = common::start_server_with_functions().await.unwrap();
+	// Connect to WebSocket
+	let mut socket = Socket::connect(&addr, SERVER, FORMAT).await.unwrap();
+	// Authenticate the connection
+	socket.send_message_signin(USER, PASS, None, None, None).await.unwrap();
+	// Specify a namespace and database
+	socket.send_message_use(Some(NS), Some(DB)).await.unwrap();
+	// Define a function with two arguments
+	socket
+		.send_message_query(
+			"DEFINE FUNCTION fn::greet($name: string, $times: option<int>) -> string { RETURN $name; }",
+		)
+		.await
+		.unwrap();
+	// Describe the function instead of running it
+	let res = socket
+		.send_request("run", json!(["fn::greet", null, null, { "describe": true }]))
+		.await
+		.unwrap();
+	assert!(res["result"].is_object(), "result: {res:?}");
+	let res = &res["result"];
+	assert_eq!(res["name"], json!("fn::greet"), "result: {res:?}");
+	assert_eq!(
+		res["args"],
+		json!([
+			{ "name": "name", "kind": "string" },
+			{ "name": "times", "kind": "option<int>" },
+		]),
+		"result: {res:?}"
+	);
+	assert_eq!(res["returns"], json!("string"), "result: {res:?}");
+	// Unknown functions are reported
+	let res = socket
+		.send_request("run", json!(["fn::unknown", null, null, { "describe": true }]))
+		.await
+		.unwrap();
+	let msg = res["error"]["message"].as_str().unwrap();
+	assert!(msg.contains("The function 'fn::unknown' does not exist"), "result: {res:?}");
+	// Only custom functions can be described
+	let res = socket
+		.send_request("run", json!(["math::abs", null, null, { "describe": true }]))
+		.await
+		.unwrap();
+	assert!(res["error"].is_object(), "result: {res:?}");
+	// Test passed
+	server.finish().unwrap();
+}
+
+#[test(tokio::test)]
+async fn run_named_arguments() {
+	// Setup database server
+	let (addr, mut server) = common::start_server_with_functions().await.unwrap();
+	// Connect to WebSocket
+	let mut socket = Socket::connect(&addr, SERVER, FORMAT).await.unwrap();
+	// Authenticate the connection
+	socket.send_message_signin(USER, PASS, None, None, None).await.unwrap();
+	// Specify a namespace and database
+	socket.send_message_use(Some(NS), Some(DB)).await.unwrap();
+	// Define a function with a required and an optional argument
+	socket
+		.send_message_query(
+			"DEFINE FUNCTION fn::greet($name: string, $greeting: option<string>) { RETURN (($greeting ?? 'Hello') + ', ' + $name); }",
+		)
+		.await
+		.unwrap();
+	// Call the function with positional arguments
+	let res =
+		socket.send_request("run", json!(["fn::greet", null, ["Tobie", "Hi"]])).await.unwrap();
+	assert_eq!(res["result"], json!("Hi, Tobie"), "result: {res:?}");
+	// Call the function with named arguments in any order
+	let res = socket
+		.send_request("run", json!(["fn::greet", null, { "greeting": "Hi", "name": "Tobie" }]))
+		.await
+		.unwrap();
+	assert_eq!(res["result"], json!("Hi, Tobie"), "result: {res:?}");
+	// Optional arguments can be left out
+	let res =
+		socket.send_request("run", json!(["fn::greet", null, { "name": "Tobie" }])).await.unwrap();
+	assert_eq!(res["result"], json!("Hello, Tobie"), "result: {res:?}");
+	// Required arguments must be provided
+	let res =
+		socket.send_request("run", json!(["fn::greet", null, { "greeting": "Hi" }])).await.unwrap();
+	assert!(res["error"].is_object(), "result: {res:?}");
+	// Unknown arguments are rejected
+	let res = socket
+		.send_request("run", json!(["fn::greet", null, { "name": "Tobie", "other": 1 }]))
+		.await
+		.unwrap();
+	assert!(res["error"].is_object(), "result: {res:?}");
+	// Only custom functions accept named arguments
+	let res =
+		socket.send_request("run", json!(["math::abs", null, { "value": -1 }])).await.unwrap();
+	assert!(res["error"].is_object(), "result: {res:?}");
+	// Test passed
+	server.finish().unwrap();
+}
+
 #[test(tokio::test)]
 async fn relate_rpc() {
 	// Setup database server